@@ -0,0 +1,238 @@
+//! Finds every `src/dayNN.rs` and generates the `mod` declarations and `Runner` registrations
+//! for them, so adding a day doesn't require editing `main.rs`. Days from other years live in
+//! `src/yYYYY/dayNN.rs`. Any `pub fn part1_<name>` or `part2_<name>` in a day is registered as
+//! another variant of that part, a `pub const INPUT: Normalize` sets how its input is
+//! normalized, and a `pub fn generate` makes random inputs for `fuzz`.
+//!
+//! Also generates a test for every answer recorded in `answers/`, named like
+//! `day07_part1_sample` (or `y2024_day07_part1_sample`), so `cargo test day07` checks just
+//! that day. Tests whose input isn't
+//! in `input/` are ignored rather than failing.
+
+use std::{
+    env,
+    fmt::Write,
+    fs,
+    path::{Path, PathBuf},
+};
+
+fn is_day_module(name: &str) -> bool {
+    name.strip_prefix("day")
+        .is_some_and(|number| number.len() == 2 && number.bytes().all(|b| b.is_ascii_digit()))
+}
+
+struct Day {
+    /// `None` for the days directly in `src/`, which are for the current year.
+    year: Option<u32>,
+    name: String,
+    path: PathBuf,
+}
+
+impl Day {
+    /// The name the runner knows the day by, which is also its path under `input/` and
+    /// `answers/`.
+    fn key(&self) -> String {
+        match self.year {
+            None => self.name.clone(),
+            Some(year) => format!("{year}/{}", self.name),
+        }
+    }
+
+    fn module(&self) -> String {
+        match self.year {
+            None => self.name.clone(),
+            Some(year) => format!("y{year}::{}", self.name),
+        }
+    }
+}
+
+/// `(part, name)` for every `pub fn partN_<name>(` in a day's source.
+fn variants(source: &str) -> Vec<(usize, String)> {
+    source
+        .lines()
+        .filter_map(|line| {
+            let rest = line.trim_start().strip_prefix("pub fn part")?;
+            let (part, rest) = rest.split_at_checked(1)?;
+            let name = rest.strip_prefix('_')?.split_once('(')?.0;
+            let valid =
+                !name.is_empty() && name.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'_');
+            let part = part.parse().ok().filter(|part| (1..=2).contains(part))?;
+            valid.then(|| (part, name.to_string()))
+        })
+        .collect()
+}
+
+fn days_in(dir: &Path, year: Option<u32>) -> Vec<Day> {
+    let mut days = fs::read_dir(dir)
+        .expect("Failed to read src")
+        .map(|entry| entry.expect("Failed to read src entry").path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .filter_map(|path| {
+            let name = path.file_stem()?.to_str()?.to_string();
+            is_day_module(&name).then_some(Day { year, name, path })
+        })
+        .collect::<Vec<_>>();
+    days.sort_by(|a, b| a.name.cmp(&b.name));
+    days
+}
+
+/// Years other than the current one, from `src/yYYYY/` directories.
+fn other_years(src: &Path) -> Vec<(u32, PathBuf)> {
+    let mut years = fs::read_dir(src)
+        .expect("Failed to read src")
+        .map(|entry| entry.expect("Failed to read src entry").path())
+        .filter(|path| path.is_dir())
+        .filter_map(|path| {
+            let year = path
+                .file_name()?
+                .to_str()?
+                .strip_prefix('y')?
+                .parse()
+                .ok()?;
+            Some((year, path))
+        })
+        .collect::<Vec<_>>();
+    years.sort();
+    years
+}
+
+fn main() {
+    let src = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("src");
+    println!("cargo:rerun-if-changed={}", src.display());
+
+    let mut generated = String::new();
+    let mut days = days_in(&src, None);
+    for day in &days {
+        writeln!(
+            generated,
+            "#[path = {:?}]\nmod {};",
+            day.path.display().to_string(),
+            day.name
+        )
+        .unwrap();
+    }
+    for (year, dir) in other_years(&src) {
+        println!("cargo:rerun-if-changed={}", dir.display());
+        let year_days = days_in(&dir, Some(year));
+        writeln!(generated, "mod y{year} {{").unwrap();
+        for day in &year_days {
+            writeln!(
+                generated,
+                "    #[path = {:?}]\n    pub mod {};",
+                day.path.display().to_string(),
+                day.name
+            )
+            .unwrap();
+        }
+        writeln!(generated, "}}").unwrap();
+        days.extend(year_days);
+    }
+
+    writeln!(generated, "\nfn register_days(runner: &mut Runner) {{").unwrap();
+    for day in &days {
+        let year = match day.year {
+            None => "input::YEAR".to_string(),
+            Some(year) => year.to_string(),
+        };
+        let source = fs::read_to_string(&day.path).expect("Failed to read day");
+        let normalize = if source
+            .lines()
+            .any(|line| line.trim_start().starts_with("pub const INPUT:"))
+        {
+            format!("{}::INPUT", day.module())
+        } else {
+            "input::Normalize::default()".to_string()
+        };
+        writeln!(
+            generated,
+            "    runner.register_day({year}, {:?}, {normalize}, {module}::part1, {module}::part2);",
+            day.name,
+            module = day.module()
+        )
+        .unwrap();
+        if source
+            .lines()
+            .any(|line| line.trim_start().starts_with("pub fn generate("))
+        {
+            writeln!(
+                generated,
+                "    runner.register_generator({year}, {:?}, {}::generate);",
+                day.name,
+                day.module()
+            )
+            .unwrap();
+        }
+        for (part, variant) in variants(&source) {
+            writeln!(
+                generated,
+                "    runner.register_variant({year}, {:?}, {part}, {variant:?}, {}::part{part}_{variant});",
+                day.name,
+                day.module()
+            )
+            .unwrap();
+        }
+    }
+    writeln!(generated, "}}").unwrap();
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap());
+    fs::write(out.join("days.rs"), generated).expect("Failed to write days.rs");
+    fs::write(out.join("day_tests.rs"), day_tests(&days)).expect("Failed to write day_tests.rs");
+}
+
+/// The input file a key like `part1.sample-small` is checked against.
+fn input_file(day: &str, key: &str) -> String {
+    match key.split_once('.') {
+        None => format!("{day}.txt"),
+        Some((_, input)) => format!("{day}-{input}.txt"),
+    }
+}
+
+fn day_tests(days: &[Day]) -> String {
+    let root = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let answers = root.join("answers");
+    let inputs = root.join("input");
+    // cargo treats a missing path as always changed, which would rebuild on every run
+    for dir in [&answers, &inputs].into_iter().filter(|dir| dir.exists()) {
+        println!("cargo:rerun-if-changed={}", dir.display());
+    }
+
+    let mut generated = String::new();
+    for day in days {
+        let day = &day.key();
+        let Ok(contents) = fs::read_to_string(answers.join(day).with_extension("txt")) else {
+            continue;
+        };
+        let keys = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| Some(line.split_once('=')?.0.trim()));
+        for key in keys {
+            let Some((part, input)) = key
+                .strip_prefix("part")
+                .map(|rest| rest.split_once('.').unwrap_or((rest, "real")))
+            else {
+                continue;
+            };
+            // answers rejected by `submit`, which have nothing to check
+            if matches!(input, "wrong" | "too-high" | "too-low") {
+                continue;
+            }
+            let mut name =
+                format!("{day}_{key}").replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+            if name.starts_with(|c: char| c.is_ascii_digit()) {
+                name.insert(0, 'y');
+            }
+            let file = input_file(day, key);
+            if !inputs.join(&file).exists() {
+                writeln!(generated, "#[ignore = \"input/{file} is missing\"]").unwrap();
+            }
+            writeln!(
+                generated,
+                "#[test]\nfn {name}() {{\n    check({day:?}, {part}, {input:?});\n}}\n"
+            )
+            .unwrap();
+        }
+    }
+    generated
+}
@@ -0,0 +1,114 @@
+//! Known-good answers, stored per day in `answers/<day>.txt`.
+//!
+//! Each line is `<key> = <answer>`, where the key is `part1`/`part2` for the
+//! real input, `part1.sample`/`part2.sample` for the sample input, and
+//! `part1.sample-<name>` for a named sample. Blank lines and lines starting
+//! with `#` are kept as they are when answers are saved. Inputs given with `--input` have no recorded answers.
+//!
+//! Answers rejected by `submit` are kept too, as any number of `part1.wrong`,
+//! `part1.too-high` and `part1.too-low` lines.
+
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    input::InputKind,
+    key_value::{self, Line},
+};
+
+pub fn key(part: usize, input: &InputKind) -> Option<String> {
+    match input {
+        InputKind::Real => Some(format!("part{part}")),
+        InputKind::Sample(_) => Some(format!("part{part}.{input}")),
+        InputKind::File { .. } | InputKind::Stdin { .. } => None,
+    }
+}
+
+pub struct Answers {
+    path: PathBuf,
+    /// Every line of the file, so comments survive saving.
+    lines: Vec<Line>,
+}
+
+impl Answers {
+    pub fn path_for(day: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("answers")
+            .join(day)
+            .with_extension("txt")
+    }
+
+    /// Loads the answers for a day. A missing file is treated as having no answers yet.
+    pub fn load(day: &str) -> anyhow::Result<Self> {
+        Self::load_from(Self::path_for(day))
+    }
+
+    fn load_from(path: PathBuf) -> anyhow::Result<Self> {
+        let lines = key_value::read_lines(&path)?;
+        Ok(Self { path, lines })
+    }
+
+    fn entries(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.lines.iter().filter_map(Line::entry)
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    pub fn get_all<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a str> {
+        self.entries()
+            .filter(move |(k, _)| *k == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Replaces the answer for `key` where it is in the file, or adds it at the end.
+    pub fn set(&mut self, key: &str, answer: impl Display) {
+        let answer = answer.to_string();
+        let existing = self.lines.iter_mut().find_map(|line| match line {
+            Line::Entry(k, v) if k == key => Some(v),
+            _ => None,
+        });
+        match existing {
+            Some(v) => *v = answer,
+            None => self.lines.push(Line::Entry(key.to_string(), answer)),
+        }
+    }
+
+    /// Adds another answer under `key`, for keys that hold several.
+    pub fn add(&mut self, key: &str, answer: impl Display) {
+        self.lines
+            .push(Line::Entry(key.to_string(), answer.to_string()));
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        key_value::write(&self.path, &self.lines)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_save_keeps_comments() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc2025-{}-answers", std::process::id()))
+            .with_extension("txt");
+        let original = "# from the first try\npart1 = 1\n\n  # samples\npart1.sample = 2\n";
+        std::fs::write(&path, original).unwrap();
+
+        let mut answers = Answers::load_from(path.clone()).unwrap();
+        answers.set("part1.sample", 3);
+        answers.set("part2", 4);
+        answers.save().unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "# from the first try\npart1 = 1\n\n  # samples\npart1.sample = 3\npart2 = 4\n"
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,224 @@
+//! Repeated timing of a single part, with optional saved baselines to compare against.
+//!
+//! Baselines live in `target/baselines/<name>.txt`, one line per part:
+//! `<day> <part> <input> <variant> <runs> <min> <median> <mean> <stddev>`, with times in
+//! nanoseconds and the input being `real`, `sample` or `sample-<name>`.
+
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::{Context, bail};
+
+use crate::{input::InputKind, runner::DEFAULT_VARIANT};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+        assert!(!samples.is_empty());
+        samples.sort();
+
+        let runs = samples.len();
+        let median = if runs.is_multiple_of(2) {
+            (samples[runs / 2 - 1] + samples[runs / 2]) / 2
+        } else {
+            samples[runs / 2]
+        };
+        let mean = samples.iter().sum::<Duration>() / runs as u32;
+        let variance = if runs > 1 {
+            samples
+                .iter()
+                .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+                .sum::<f64>()
+                / (runs - 1) as f64
+        } else {
+            0.0
+        };
+
+        Self {
+            runs,
+            min: samples[0],
+            median,
+            mean,
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {}µs  median {}µs  mean {}µs ± {}µs  ({} runs)",
+            self.min.as_micros(),
+            self.median.as_micros(),
+            self.mean.as_micros(),
+            self.stddev.as_micros(),
+            self.runs
+        )
+    }
+}
+
+pub struct BenchResult {
+    pub day: String,
+    pub part: usize,
+    pub variant: String,
+    pub input: InputKind,
+    pub answer: String,
+    pub stats: Stats,
+    pub baseline: Option<Stats>,
+}
+
+impl Display for BenchResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} part {}", self.day, self.part)?;
+        if self.variant != DEFAULT_VARIANT {
+            write!(f, " ({})", self.variant)?;
+        }
+        if let InputKind::Sample(Some(_)) = &self.input {
+            write!(f, " [{}]", self.input)?;
+        }
+        write!(f, ": {}\t{}", self.answer, self.stats)?;
+        if let Some(baseline) = &self.baseline {
+            let delta = (self.stats.median.as_secs_f64() - baseline.median.as_secs_f64())
+                / baseline.median.as_secs_f64()
+                * 100.0;
+            write!(f, "\t{delta:+.1}% median vs baseline")?;
+        }
+        Ok(())
+    }
+}
+
+type BaselineKey = (String, usize, InputKind, String);
+
+pub struct Baseline {
+    path: PathBuf,
+    entries: HashMap<BaselineKey, Stats>,
+}
+
+impl Baseline {
+    /// Loads a named baseline. A missing file is treated as an empty baseline.
+    pub fn load(name: &str) -> anyhow::Result<Self> {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("target")
+            .join("baselines")
+            .join(name)
+            .with_extension("txt");
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e).with_context(|| format!("reading {}", path.display())),
+        };
+
+        let mut entries = HashMap::new();
+        for (number, line) in contents.lines().enumerate() {
+            let fields = line.split_whitespace().collect::<Vec<_>>();
+            let [day, part, input, variant, runs, min, median, mean, stddev] = fields[..] else {
+                bail!("{}:{}: malformed baseline", path.display(), number + 1);
+            };
+            let nanos = |s: &str| s.parse().map(Duration::from_nanos);
+            entries.insert(
+                (
+                    day.to_string(),
+                    part.parse()?,
+                    input.parse()?,
+                    variant.to_string(),
+                ),
+                Stats {
+                    runs: runs.parse()?,
+                    min: nanos(min)?,
+                    median: nanos(median)?,
+                    mean: nanos(mean)?,
+                    stddev: nanos(stddev)?,
+                },
+            );
+        }
+
+        Ok(Self { path, entries })
+    }
+
+    pub fn get(&self, day: &str, part: usize, input: &InputKind, variant: &str) -> Option<Stats> {
+        self.entries
+            .get(&(day.to_string(), part, input.clone(), variant.to_string()))
+            .copied()
+    }
+
+    pub fn set(&mut self, result: &BenchResult) {
+        self.entries.insert(
+            (
+                result.day.clone(),
+                result.part,
+                result.input.clone(),
+                result.variant.clone(),
+            ),
+            result.stats,
+        );
+    }
+
+    pub fn save(&self) -> anyhow::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("creating {}", parent.display()))?;
+        }
+        let mut keys = self.entries.keys().collect::<Vec<_>>();
+        keys.sort();
+        let contents: String = keys
+            .into_iter()
+            .map(|key @ (day, part, input, variant)| {
+                let stats = &self.entries[key];
+                format!(
+                    "{day} {part} {input} {variant} {} {} {} {} {}\n",
+                    stats.runs,
+                    stats.min.as_nanos(),
+                    stats.median.as_nanos(),
+                    stats.mean.as_nanos(),
+                    stats.stddev.as_nanos()
+                )
+            })
+            .collect();
+        std::fs::write(&self.path, contents)
+            .with_context(|| format!("writing {}", self.path.display()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let ms = |samples: &[u64]| {
+            samples
+                .iter()
+                .map(|&ms| Duration::from_millis(ms))
+                .collect()
+        };
+
+        let stats = Stats::from_samples(ms(&[4, 1, 3, 2]));
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        // sample standard deviation: sqrt((1.5² + 0.5² + 0.5² + 1.5²) / 3) ms
+        assert_eq!(stats.stddev.as_micros(), 1290);
+
+        let stats = Stats::from_samples(ms(&[5, 1, 3]));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.stddev.as_micros(), 2000);
+
+        let stats = Stats::from_samples(ms(&[5]));
+        assert_eq!(stats.median, Duration::from_millis(5));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+}
@@ -0,0 +1,52 @@
+//! Cooperative cancellation for parts that the runner has given up on.
+//!
+//! A part that times out keeps running on its worker thread, since threads can't be killed.
+//! Long-running loops call [`check_cancelled`], which unwinds out of the part once the runner
+//! has cancelled it so the abandoned thread stops using CPU.
+
+use std::{
+    cell::RefCell,
+    panic,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+
+#[derive(Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Unwind payload used to abandon a cancelled part without running the panic hook.
+pub struct Cancelled;
+
+thread_local! {
+    static TOKEN: RefCell<Option<CancelToken>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with `token` as the current thread's cancellation token.
+pub fn with_token<T>(token: CancelToken, f: impl FnOnce() -> T) -> T {
+    TOKEN.with_borrow_mut(|current| *current = Some(token));
+    let result = f();
+    TOKEN.with_borrow_mut(|current| *current = None);
+    result
+}
+
+/// Unwinds out of the current part if the runner has cancelled it. Cheap enough to call
+/// once per iteration of a search loop.
+pub fn check_cancelled() {
+    let cancelled =
+        TOKEN.with_borrow(|token| token.as_ref().is_some_and(CancelToken::is_cancelled));
+    if cancelled {
+        panic::resume_unwind(Box::new(Cancelled));
+    }
+}
@@ -0,0 +1,34 @@
+//! Checked numeric conversions, for answers computed in one integer type and returned in
+//! another. Unlike an `as` cast, a value that doesn't fit is an error instead of wrapping or
+//! truncating.
+
+use std::{any::type_name, fmt::Display};
+
+use anyhow::anyhow;
+
+pub trait CheckedInto: Sized {
+    /// Converts to `U`, failing with the value and type if it doesn't fit.
+    fn checked_into<U: TryFrom<Self>>(self) -> anyhow::Result<U>;
+}
+
+impl<T: Copy + Display> CheckedInto for T {
+    fn checked_into<U: TryFrom<Self>>(self) -> anyhow::Result<U> {
+        U::try_from(self).map_err(|_| anyhow!("{self} doesn't fit in {}", type_name::<U>()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_checked_into() {
+        assert_eq!(5isize.checked_into::<u64>().unwrap(), 5);
+        assert!((-1isize).checked_into::<usize>().is_err());
+        assert!(u64::MAX.checked_into::<u32>().is_err());
+
+        let big = num::BigInt::from(u64::MAX) + 1u8;
+        assert!((&big).checked_into::<u64>().is_err());
+        assert_eq!((&big).checked_into::<u128>().unwrap(), u64::MAX as u128 + 1);
+    }
+}
@@ -1,10 +1,10 @@
 #[allow(unused)]
 use crate::prelude::*;
 
-pub fn part1(input: &str, _is_sample: bool) -> usize {
+pub fn part1(input: &str, _params: &Params) -> usize {
     todo!()
 }
 
-pub fn part2(input: &str, _is_sample: bool) -> usize {
+pub fn part2(input: &str, _params: &Params) -> usize {
     todo!()
 }
@@ -1,36 +1,48 @@
 #[allow(unused)]
 use crate::prelude::*;
 
-pub fn part1(input: &str, _is_sample: bool) -> usize {
+fn parse_rotation(line: &str) -> anyhow::Result<(isize, isize)> {
+    let (letter, num_st) = line
+        .split_at_checked(1)
+        .with_context(|| format!("empty rotation in {line:?}"))?;
+    let dir = match letter {
+        "L" => -1,
+        "R" => 1,
+        _ => bail!("unknown direction {letter}"),
+    };
+    let distance = num_st
+        .parse::<isize>()
+        .with_context(|| format!("invalid distance in {line:?}"))?;
+    Ok((dir, distance))
+}
+
+fn parse(input: &str) -> anyhow::Result<Vec<(isize, isize)>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(number, line)| parse_rotation(line).with_context(|| format!("line {}", number + 1)))
+        .collect()
+}
+
+pub fn part1(input: &str, _params: &Params) -> anyhow::Result<usize> {
     let mut now: isize = 50;
-    let sequence = input.lines().map(|line| {
-        let (letter, num_st) = line.split_at(1);
-        let dir = match letter {
-            "L" => -1,
-            "R" => 1,
-            _ => panic!("unknown direction {letter}"),
-        };
-        now = (now + num_st.parse::<isize>().unwrap() * dir).rem_euclid(100);
+    let sequence = parse(input)?.into_iter().map(|(dir, distance)| {
+        now = (now + distance * dir).rem_euclid(100);
         now
     });
 
-    sequence.filter(|&x| x == 0).count()
+    Ok(sequence.filter(|&x| x == 0).count())
 }
 
-pub fn part2(input: &str, _is_sample: bool) -> usize {
+pub fn part2(input: &str, _params: &Params) -> anyhow::Result<usize> {
     let mut now: isize = 50;
-    let zeros = input.lines().map(|line| {
-        let (letter, num_st) = line.split_at(1);
-        let dir = match letter {
-            "L" => -1,
-            "R" => 1,
-            _ => panic!("unknown direction {letter}"),
-        };
-        let distance = num_st.parse::<isize>().unwrap();
+    let zeros = parse(input)?.into_iter().map(|(dir, distance)| {
         let loops = distance / 100;
         let next = (now + distance * dir).rem_euclid(100);
 
-        let extras = if (dir.signum() != (next - now).signum() || next == 0) && now != 0 {
+        // a whole number of turns ends where it started, which `loops` already counts
+        let crossed = next != now && dir.signum() != (next - now).signum();
+        let extras = if (crossed || next == 0) && now != 0 {
             1
         } else {
             0
@@ -40,5 +52,46 @@ pub fn part2(input: &str, _is_sample: bool) -> usize {
         loops + extras
     });
 
-    zeros.sum::<isize>() as usize
+    Ok(zeros.sum::<isize>() as usize)
+}
+
+/// Every position the dial stops at turning one click at a time, and whether a rotation ends
+/// there.
+fn click_by_click(input: &str) -> anyhow::Result<Vec<(isize, bool)>> {
+    let mut now: isize = 50;
+    let mut positions = vec![];
+    for (dir, distance) in parse(input)? {
+        for click in 1..=distance {
+            now = (now + dir).rem_euclid(100);
+            positions.push((now, click == distance));
+        }
+    }
+    Ok(positions)
+}
+
+pub fn part1_reference(input: &str, _params: &Params) -> anyhow::Result<usize> {
+    let positions = click_by_click(input)?;
+    Ok(positions
+        .into_iter()
+        .filter(|&(position, end)| end && position == 0)
+        .count())
+}
+
+pub fn part2_reference(input: &str, _params: &Params) -> anyhow::Result<usize> {
+    let positions = click_by_click(input)?;
+    Ok(positions
+        .into_iter()
+        .filter(|&(position, _)| position == 0)
+        .count())
+}
+
+/// A few rotations of up to three full turns each.
+pub fn generate(rng: &mut ChaCha8Rng) -> String {
+    let rotations = rng.gen_range(1..=20);
+    (0..rotations)
+        .map(|_| {
+            let direction = if rng.gen_bool(0.5) { 'L' } else { 'R' };
+            format!("{direction}{}", rng.gen_range(1..=300))
+        })
+        .join("\n")
 }
@@ -1,3 +1,5 @@
+use std::collections::HashSet;
+
 #[allow(unused)]
 use crate::prelude::*;
 
@@ -12,23 +14,66 @@ fn any_repeated(value: &usize) -> bool {
     (1..=digits.len() / 2).any(|length| digits.chunks(length).all_equal())
 }
 
+fn ranges(input: &str) -> impl Iterator<Item = (usize, usize)> {
+    input.split(",").map(|range_str| {
+        let (low, high) = range_str.split("-").collect_tuple().unwrap();
+        (low.parse().unwrap(), high.parse().unwrap())
+    })
+}
+
 fn sum_invalid(input: &str, invalid_fn: fn(&usize) -> bool) -> usize {
-    let ranges = input.split(",");
-    ranges
-        .map(|range_str| {
-            let (low, high) = range_str.split("-").collect_tuple().unwrap();
-            let low = low.parse().unwrap();
-            let high = high.parse().unwrap();
-
-            (low..=high).filter(invalid_fn).sum::<usize>()
+    ranges(input)
+        .map(|(low, high)| {
+            (low..=high)
+                .filter(|value| {
+                    check_cancelled();
+                    invalid_fn(value)
+                })
+                .sum::<usize>()
         })
         .sum()
 }
 
-pub fn part1(input: &str, _is_sample: bool) -> usize {
+pub fn part1(input: &str, _params: &Params) -> usize {
     sum_invalid(input, two_repeated)
 }
 
-pub fn part2(input: &str, _is_sample: bool) -> usize {
+pub fn part2(input: &str, _params: &Params) -> usize {
     sum_invalid(input, any_repeated)
 }
+
+/// Every number in `low..=high` made of a block of digits repeated some number of times that
+/// `repeats` allows, found by building them rather than checking every number in the range.
+fn repeated_in_range(low: usize, high: usize, repeats: fn(usize) -> bool) -> HashSet<usize> {
+    let mut found = HashSet::new();
+    let max_digits = high.to_string().len();
+    for digits in 2..=max_digits {
+        for block in (1..=digits / 2).filter(|block| digits % block == 0) {
+            if !repeats(digits / block) {
+                continue;
+            }
+            // block * multiplier repeats the block, e.g. 12 * 10101 = 121212
+            let multiplier = (0..digits / block)
+                .map(|i| 10usize.pow((i * block) as u32))
+                .sum::<usize>();
+            let smallest = 10usize.pow(block as u32 - 1).max(low.div_ceil(multiplier));
+            let largest = (10usize.pow(block as u32) - 1).min(high / multiplier);
+            found.extend((smallest..=largest).map(|block| block * multiplier));
+        }
+    }
+    found
+}
+
+pub fn part1_construct(input: &str, _params: &Params) -> usize {
+    ranges(input)
+        .map(|(low, high)| repeated_in_range(low, high, |repeats| repeats == 2))
+        .map(|found| found.into_iter().sum::<usize>())
+        .sum()
+}
+
+pub fn part2_construct(input: &str, _params: &Params) -> usize {
+    ranges(input)
+        .map(|(low, high)| repeated_in_range(low, high, |repeats| repeats >= 2))
+        .map(|found| found.into_iter().sum::<usize>())
+        .sum()
+}
@@ -28,14 +28,14 @@ fn largest_joltage(haystack: &[Char], batteries: usize, prefix: usize) -> usize
     }
 }
 
-pub fn part1(input: &str, _is_sample: bool) -> usize {
+pub fn part1(input: &str, _params: &Params) -> usize {
     input
         .lines()
         .map(|line| largest_joltage(line.as_ascii().unwrap(), 2, 0))
         .sum()
 }
 
-pub fn part2(input: &str, _is_sample: bool) -> usize {
+pub fn part2(input: &str, _params: &Params) -> usize {
     input
         .lines()
         .map(|line| largest_joltage(line.as_ascii().unwrap(), 12, 0))
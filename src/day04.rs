@@ -10,7 +10,7 @@ fn accessible(grid: &Grid<char>) -> impl Iterator<Item = Cell<'_, char>> {
         .filter(|cell| is_roll(cell) && cell.neighbors().filter(is_roll).count() < 4)
 }
 
-pub fn part1(input: &str, _is_sample: bool) -> usize {
+pub fn part1(input: &str, _params: &Params) -> usize {
     accessible(&Grid::new_with_lines(input.lines())).count()
 }
 
@@ -26,6 +26,6 @@ fn remove_accessible(mut grid: Grid<char>) -> usize {
     }
 }
 
-pub fn part2(input: &str, _is_sample: bool) -> usize {
+pub fn part2(input: &str, _params: &Params) -> usize {
     remove_accessible(Grid::new_with_lines(input.lines()))
 }
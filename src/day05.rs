@@ -24,7 +24,7 @@ fn parser<'s>() -> impl Parser<'s, &'s str, (Ranges, Vec<usize>), extra::Err<Ric
     ranges.then_ignore(just("\n")).then(numbers)
 }
 
-pub fn part1(input: &str, _is_sample: bool) -> usize {
+pub fn part1(input: &str, _params: &Params) -> usize {
     let (ranges, candidates) = parser().parse(input).unwrap();
 
     candidates
@@ -38,7 +38,7 @@ fn union(a: &RangeInclusive<usize>, b: &RangeInclusive<usize>) -> Option<RangeIn
         .then(|| *a.start().min(b.start())..=*(a.end().max(b.end())))
 }
 
-pub fn part2(input: &str, _is_sample: bool) -> usize {
+pub fn part2(input: &str, _params: &Params) -> usize {
     let (ranges, _candidates) = parser().parse(input).unwrap();
 
     let disjoint_ranges = ranges.into_iter().fold(vec![], |accum, mut range| {
@@ -3,7 +3,10 @@ use std::ops::{Add, Mul};
 #[allow(unused)]
 use crate::prelude::*;
 
-pub fn part1(input: &str, _is_sample: bool) -> usize {
+/// Columns are right-aligned, so leading spaces on the first line matter.
+pub const INPUT: Normalize = Normalize::TrimNewline;
+
+pub fn part1(input: &str, _params: &Params) -> usize {
     let mut lines = input.lines();
     let numbers = lines
         .take_while_ref(|line| !line.starts_with(&['*', '+']))
@@ -28,7 +31,7 @@ pub fn part1(input: &str, _is_sample: bool) -> usize {
         .sum()
 }
 
-pub fn part2(input: &str, _is_sample: bool) -> usize {
+pub fn part2(input: &str, _params: &Params) -> usize {
     let mut lines = input.lines();
     let numbers = lines
         .take_while_ref(|line| !line.starts_with(&['*', '+']))
@@ -28,7 +28,7 @@ fn count_splits(previous_row: HashSet<Cell<char>>) -> usize {
     count + count_splits(split)
 }
 
-pub fn part1(input: &str, _is_sample: bool) -> usize {
+pub fn part1(input: &str, _params: &Params) -> usize {
     let grid = Grid::new_with_lines(input.lines());
 
     let start = grid
@@ -59,7 +59,7 @@ fn count_timelines<'a>(
     }
 }
 
-pub fn part2(input: &str, _is_sample: bool) -> usize {
+pub fn part2(input: &str, _params: &Params) -> usize {
     let grid = Grid::new_with_lines(input.lines());
 
     let start = grid.cells().find(|cell| *cell.contents() == 'S');
@@ -44,8 +44,8 @@ fn parse(input: &str) -> (Vec<Point3D<isize>>, Vec<(isize, usize, usize)>) {
     (points, distances)
 }
 
-pub fn part1(input: &str, is_sample: bool) -> usize {
-    let num_joins = if is_sample { 10 } else { 1000 };
+pub fn part1(input: &str, params: &Params) -> anyhow::Result<usize> {
+    let num_joins = params.get("joins")?;
     let (points, distances) = parse(input);
 
     let mut subgraph_roots = (0..points.len()).collect_vec();
@@ -57,7 +57,7 @@ pub fn part1(input: &str, is_sample: bool) -> usize {
         }
     }
 
-    subgraph_roots
+    Ok(subgraph_roots
         .iter()
         .counts()
         .into_values()
@@ -65,10 +65,10 @@ pub fn part1(input: &str, is_sample: bool) -> usize {
         .rev()
         .take(3)
         .reduce(<usize as Mul>::mul)
-        .unwrap()
+        .unwrap())
 }
 
-pub fn part2(input: &str, _is_sample: bool) -> usize {
+pub fn part2(input: &str, _params: &Params) -> anyhow::Result<u64> {
     let (points, distances) = parse(input);
 
     let mut subgraph_roots = (0..points.len()).collect_vec();
@@ -77,9 +77,9 @@ pub fn part2(input: &str, _is_sample: bool) -> usize {
         let root_b = subgraph_roots[b];
         if root_a != root_b {
             if join(root_a, root_b, &mut subgraph_roots) == points.len() {
-                return (points[a].x * points[b].x) as usize;
+                return (points[a].x * points[b].x).checked_into();
             }
         }
     }
-    panic!("didn't make full circuit")
+    bail!("didn't make full circuit")
 }
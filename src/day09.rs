@@ -3,7 +3,7 @@ use std::iter;
 #[allow(unused)]
 use crate::prelude::*;
 
-pub fn part1(input: &str, _is_sample: bool) -> usize {
+pub fn part1(input: &str, _params: &Params) -> anyhow::Result<u64> {
     let points = input
         .lines()
         .map(|line| {
@@ -18,7 +18,8 @@ pub fn part1(input: &str, _is_sample: bool) -> usize {
         .cartesian_product(points.iter())
         .map(|(a, b)| ((a.0 - b.0 + 1) * (a.1 - b.1 + 1)).abs())
         .max()
-        .unwrap() as usize
+        .context("no points")?
+        .checked_into()
 }
 
 fn walk_between_inclusive(a: Location, b: Location) -> impl Iterator<Item = Location> {
@@ -51,7 +52,8 @@ enum Tile {
     Outside,
 }
 
-pub fn part2(input: &str, _is_sample: bool) -> usize {
+pub fn part2(input: &str, _params: &Params) -> anyhow::Result<u64> {
+    let parse = span("parse");
     let points = input
         .lines()
         .map(|line| {
@@ -63,11 +65,13 @@ pub fn part2(input: &str, _is_sample: bool) -> usize {
             point2(x, y)
         })
         .collect_vec();
+    drop(parse);
 
+    let borders = span("set borders");
     let mut tiles = Grid::new(
         Tile::Inside,
-        points.iter().map(|p| p.x).max().unwrap() as usize + 1,
-        points.iter().map(|p| p.y).max().unwrap() as usize + 1,
+        points.iter().map(|p| p.x).max().context("no points")?.checked_into::<usize>()? + 1,
+        points.iter().map(|p| p.y).max().context("no points")?.checked_into::<usize>()? + 1,
     );
 
     let pairs = points.iter().circular_tuple_windows();
@@ -77,8 +81,9 @@ pub fn part2(input: &str, _is_sample: bool) -> usize {
         }
     }
 
-    println!("set borders");
+    drop(borders);
 
+    let fill = span("fill outside");
     let grid_corners = [
         point2(0, 0),
         point2(tiles.width() as isize - 1, 0),
@@ -100,7 +105,7 @@ pub fn part2(input: &str, _is_sample: bool) -> usize {
         }
     }
 
-    println!("done fill");
+    drop(fill);
 
     fn rect_size((a, b): &(Point2D<isize>, Point2D<isize>)) -> isize {
         ((a.x - b.x).abs() + 1) * ((a.y - b.y).abs() + 1)
@@ -117,6 +122,7 @@ pub fn part2(input: &str, _is_sample: bool) -> usize {
         valid
     }
 
+    let candidates = span("sort candidates");
     let sorted_candidates = points
         .iter()
         .copied()
@@ -125,11 +131,13 @@ pub fn part2(input: &str, _is_sample: bool) -> usize {
         .rev()
         .collect_vec();
 
-    println!("have candidates");
+    drop(candidates);
 
+    let _search = span("search");
     sorted_candidates
         .iter()
         .find(|(a, b)| is_valid(*a, *b, &tiles))
         .map(rect_size)
-        .unwrap() as usize
+        .context("no valid rectangle")?
+        .checked_into()
 }
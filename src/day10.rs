@@ -14,21 +14,21 @@ struct Machine {
     joltage: JoltageVec,
 }
 
-fn parse_line(line: &str) -> Machine {
+fn parse_line(line: &str) -> anyhow::Result<Machine> {
     // [.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
 
     let mut pieces = line.split(' ');
     let lights = pieces
         .next()
-        .unwrap()
+        .context("missing lights")?
         .trim_matches(&['[', ']'])
         .chars()
         .map(|c| match c {
-            '.' => false,
-            '#' => true,
-            _ => panic!("unexpected char {c}"),
+            '.' => Ok(false),
+            '#' => Ok(true),
+            _ => Err(anyhow!("unexpected char {c}")),
         })
-        .collect_vec();
+        .collect::<anyhow::Result<Vec<_>>>()?;
 
     let buttons = pieces
         .take_while_ref(|p| p.starts_with('('))
@@ -36,30 +36,38 @@ fn parse_line(line: &str) -> Machine {
             button_str
                 .trim_matches(&['(', ')'])
                 .split(',')
-                .map(|num| num.parse::<usize>().unwrap())
-                .collect_vec()
+                .map(|num| num.parse::<usize>())
+                .collect::<Result<Vec<_>, _>>()
+                .with_context(|| format!("invalid button {button_str}"))
         })
-        .collect_vec();
+        .collect::<anyhow::Result<Vec<_>>>()?;
 
-    let joltage = pieces
-        .next()
-        .unwrap()
+    let joltage_str = pieces.next().context("missing joltage")?;
+    let joltage = joltage_str
         .trim_matches(&['{', '}'])
         .split(',')
-        .map(|num| num.parse::<u16>().unwrap())
-        .collect();
-    Machine {
+        .map(|num| num.parse::<u16>())
+        .collect::<Result<_, _>>()
+        .with_context(|| format!("invalid joltage {joltage_str}"))?;
+    Ok(Machine {
         lights,
         buttons,
         joltage,
-    }
+    })
 }
 
-pub fn part1(input: &str, _is_sample: bool) -> usize {
+fn parse(input: &str) -> anyhow::Result<Vec<Machine>> {
     input
         .lines()
-        .map(|line| {
-            let machine = parse_line(line);
+        .enumerate()
+        .map(|(number, line)| parse_line(line).with_context(|| format!("line {}", number + 1)))
+        .collect()
+}
+
+pub fn part1(input: &str, _params: &Params) -> anyhow::Result<u64> {
+    let total = parse(input)?
+        .into_iter()
+        .map(|machine| {
             let start = iter::repeat(false).take(machine.lights.len()).collect_vec();
             min_distance_to(
                 start,
@@ -80,15 +88,14 @@ pub fn part1(input: &str, _is_sample: bool) -> usize {
             )
             .unwrap()
         })
-        .sum::<u64>() as usize
+        .sum::<u64>();
+    Ok(total)
 }
 
-pub fn part2(input: &str, _is_sample: bool) -> usize {
-    input
-        .lines()
-        .map(|line| {
-            let machine = parse_line(line);
-
+pub fn part2(input: &str, _params: &Params) -> anyhow::Result<u64> {
+    parse(input)?
+        .into_iter()
+        .map(|machine| {
             let solver = z3::Optimize::new();
 
             let mut joltage_sums = vec![z3::ast::Int::from_i64(0); machine.joltage.len()];
@@ -118,7 +125,10 @@ pub fn part2(input: &str, _is_sample: bool) -> usize {
             assert!(solver.check(&[]) == z3::SatResult::Sat);
 
             let model = solver.get_model().unwrap();
-            model.eval(&total_presses, true).unwrap().as_i64().unwrap() as usize
+            model
+                .eval(&total_presses, true)
+                .and_then(|presses| presses.as_u64())
+                .context("presses out of range")
         })
         .sum()
 }
@@ -17,11 +17,11 @@ fn find_paths<'a>(input: &'a str, start: &'a str, end: &'a str) -> usize {
     })
 }
 
-pub fn part1(input: &str, _is_sample: bool) -> usize {
+pub fn part1(input: &str, _params: &Params) -> usize {
     find_paths(input, "you", "out")
 }
 
-pub fn part2(input: &str, _is_sample: bool) -> usize {
+pub fn part2(input: &str, _params: &Params) -> usize {
     let s_to_fft = find_paths(input, "svr", "fft");
     let s_to_dac = find_paths(input, "svr", "dac");
 
@@ -1,7 +1,7 @@
 #[allow(unused)]
 use crate::prelude::*;
 
-pub fn part1(input: &str, _is_sample: bool) -> usize {
+pub fn part1(input: &str, _params: &Params) -> usize {
     let sections = input.split("\n\n").collect_vec();
 
     let piece_counts = sections[..sections.len() - 1]
@@ -52,7 +52,7 @@ pub fn part1(input: &str, _is_sample: bool) -> usize {
         .sum()
 }
 
-pub fn part2(_input: &str, _is_sample: bool) -> usize {
-    println!("merry christmas");
+pub fn part2(_input: &str, _params: &Params) -> usize {
+    log::info!("merry christmas");
     0
 }
@@ -0,0 +1,161 @@
+//! `fuzz <day>`: checks a day's parts against their `reference` variants on random inputs
+//! from the day's generator.
+//!
+//! Each input is made from a `ChaCha8Rng` seeded with `--seed` plus the iteration number, so a
+//! failure can be reproduced. The first input where a variant and the reference disagree is
+//! shrunk by removing lines for as long as they still disagree, then written out as the day's
+//! `fuzz` sample so `-s` keeps running it.
+
+use std::panic;
+
+use anyhow::{Context, bail};
+use itertools::Itertools;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    input::InputKind,
+    runner::{REFERENCE_VARIANT, Runner},
+};
+
+/// The sample name failing inputs are written under.
+const SAMPLE: &str = "fuzz";
+
+fn sample() -> InputKind {
+    InputKind::Sample(Some(SAMPLE.to_string()))
+}
+
+/// How `variant` and the reference disagree on `input`, if they do. Both failing counts as
+/// agreeing, since generators don't promise every input is solvable.
+fn disagreement(
+    runner: &Runner,
+    day: &str,
+    part: usize,
+    variant: &str,
+    input: &str,
+) -> Option<String> {
+    let describe = |answer: anyhow::Result<String>| match answer {
+        Ok(answer) => answer,
+        Err(e) => format!("error ({e:#})"),
+    };
+    let reference = runner.solve(day, part, REFERENCE_VARIANT, &sample(), input);
+    let answer = runner.solve(day, part, variant, &sample(), input);
+    match (&reference, &answer) {
+        (Ok(expected), Ok(answer)) if expected == answer => None,
+        (Err(_), Err(_)) => None,
+        _ => Some(format!(
+            "{variant} gave {}, {REFERENCE_VARIANT} gave {}",
+            describe(answer),
+            describe(reference)
+        )),
+    }
+}
+
+/// Removes chunks of lines, halving the chunk size when none can go, keeping only removals
+/// after which `fails` still holds.
+fn minimize(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let mut lines = input.lines().collect_vec();
+    let mut chunk = lines.len() / 2;
+    while chunk > 0 {
+        let mut removed = false;
+        let mut start = 0;
+        while start < lines.len() {
+            let end = (start + chunk).min(lines.len());
+            let candidate = [&lines[..start], &lines[end..]].concat();
+            if !candidate.is_empty() && fails(&candidate.join("\n")) {
+                lines = candidate;
+                removed = true;
+            } else {
+                start = end;
+            }
+        }
+        chunk = if removed {
+            chunk.min(lines.len() / 2)
+        } else {
+            chunk / 2
+        };
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Runs up to `iterations` random inputs through each of `parts`, returning whether any
+/// variant disagreed with its reference.
+pub fn fuzz(
+    runner: &Runner,
+    day: &str,
+    parts: &[usize],
+    iterations: u64,
+    seed: u64,
+) -> anyhow::Result<bool> {
+    let generator = runner.generator(day)?;
+    let mut checks = vec![];
+    for &part in parts {
+        let variants = runner.variant_names(day, part)?;
+        if !variants.iter().any(|variant| variant == REFERENCE_VARIANT) {
+            println!("{day} part {part}: no part{part}_{REFERENCE_VARIANT} to check against");
+            continue;
+        }
+        checks.extend(
+            variants
+                .into_iter()
+                .filter(|variant| variant != REFERENCE_VARIANT)
+                .map(|variant| (part, variant)),
+        );
+    }
+    if checks.is_empty() {
+        bail!("{day} has nothing to fuzz");
+    }
+
+    // parts panicking on odd inputs would otherwise print every panic
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let failure = (0..iterations).find_map(|iteration| {
+        let input = generator(&mut ChaCha8Rng::seed_from_u64(seed.wrapping_add(iteration)));
+        checks.iter().find_map(|(part, variant)| {
+            disagreement(runner, day, *part, variant, &input)?;
+            let minimized = minimize(&input, |input| {
+                disagreement(runner, day, *part, variant, input).is_some()
+            });
+            let reason = disagreement(runner, day, *part, variant, &minimized)?;
+            Some((iteration, *part, reason, minimized))
+        })
+    });
+    panic::set_hook(hook);
+
+    let Some((iteration, part, reason, minimized)) = failure else {
+        println!(
+            "{day}: {} checks agreed on {iterations} inputs",
+            checks.len()
+        );
+        return Ok(false);
+    };
+    let path = runner.inputs.path(day, &sample());
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("creating {}", parent.display()))?;
+    }
+    std::fs::write(&path, &minimized).with_context(|| format!("writing {}", path.display()))?;
+    println!(
+        "{day} part {part}: {reason} on seed {}, minimized to {} lines in {}",
+        seed.wrapping_add(iteration),
+        minimized.lines().count(),
+        path.display()
+    );
+    Ok(true)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_minimize() {
+        let input = (1..=20).join("\n");
+        // fails whenever 7 and 13 are both present
+        let minimized = minimize(&input, |input| {
+            let lines = input.lines().collect_vec();
+            lines.contains(&"7") && lines.contains(&"13")
+        });
+        assert_eq!(minimized, "7\n13\n");
+    }
+}
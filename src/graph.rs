@@ -8,6 +8,8 @@ use std::{
 
 use itertools::Itertools;
 
+use crate::cancel::check_cancelled;
+
 pub fn find<V, EdgeIterator>(
     start: V,
     mut edges: impl FnMut(V) -> EdgeIterator,
@@ -22,6 +24,7 @@ where
 
     std::iter::from_fn(move || {
         while let Some(node) = queue.pop() {
+            check_cancelled();
             if visited.contains(&node) {
                 continue;
             }
@@ -55,6 +58,7 @@ where
     let mut queue = BinaryHeap::new();
     queue.push(Reverse((0, start)));
     while let Some(Reverse((distance, node))) = queue.pop() {
+        check_cancelled();
         if let Some(&previous_distance) = distances.get(&node) {
             if distance >= previous_distance {
                 continue;
@@ -196,6 +200,7 @@ where
     let mut path_and_local_queues = vec![(start, first_edges, false)];
     return iter::from_fn(move || {
         while let Some((node, alternatives, seen)) = path_and_local_queues.last_mut() {
+            check_cancelled();
             if *node == end && !*seen {
                 *seen = true;
                 return Some(
@@ -0,0 +1,211 @@
+//! Timing history across commits, for spotting when a part got slower.
+//!
+//! With `--history`, every timed result is appended to `history.csv` in the repo, along with
+//! the git commit it ran at and whether the tree had uncommitted changes. `report` then shows
+//! how each part's median time changed from commit to commit.
+
+use std::{
+    collections::HashMap,
+    fs::OpenOptions,
+    path::{Path, PathBuf},
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::{Context, bail};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::runner::{DEFAULT_VARIANT, RunResult};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Entry {
+    timestamp: u64,
+    commit: String,
+    dirty: bool,
+    day: String,
+    part: usize,
+    input: String,
+    elapsed_ns: u64,
+    variant: String,
+}
+
+/// The commit being run and whether the working tree differs from it.
+#[derive(Debug, Clone)]
+pub struct Revision {
+    pub commit: String,
+    pub dirty: bool,
+}
+
+impl Revision {
+    pub fn current() -> anyhow::Result<Self> {
+        let git = |args: &[&str]| -> anyhow::Result<String> {
+            let output = Command::new("git")
+                .args(args)
+                .current_dir(env!("CARGO_MANIFEST_DIR"))
+                .output()
+                .context("running git")?;
+            if !output.status.success() {
+                bail!(
+                    "git {}: {}",
+                    args.join(" "),
+                    String::from_utf8_lossy(&output.stderr).trim()
+                );
+            }
+            Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+        };
+        Ok(Self {
+            commit: git(&["rev-parse", "--short=10", "HEAD"])?,
+            dirty: !git(&["status", "--porcelain", "--untracked-files=no"])?.is_empty(),
+        })
+    }
+
+    fn label(commit: &str, dirty: bool) -> String {
+        if dirty {
+            format!("{commit}+")
+        } else {
+            commit.to_string()
+        }
+    }
+}
+
+pub struct History {
+    path: PathBuf,
+    revision: Revision,
+    writer: Option<csv::Writer<std::fs::File>>,
+}
+
+impl History {
+    pub fn path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("history.csv")
+    }
+
+    pub fn open() -> anyhow::Result<Self> {
+        Ok(Self {
+            path: Self::path(),
+            revision: Revision::current()?,
+            writer: None,
+        })
+    }
+
+    /// Appends a result's timing. Results without an answer aren't worth comparing.
+    pub fn append(&mut self, result: &RunResult) -> anyhow::Result<()> {
+        if result.answer.is_none() {
+            return Ok(());
+        }
+        if self.writer.is_none() {
+            let exists = self.path.exists();
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.path)
+                .with_context(|| format!("opening {}", self.path.display()))?;
+            self.writer = Some(
+                csv::WriterBuilder::new()
+                    .has_headers(!exists)
+                    .from_writer(file),
+            );
+        }
+
+        let writer = self.writer.as_mut().unwrap();
+        writer.serialize(Entry {
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            commit: self.revision.commit.clone(),
+            dirty: self.revision.dirty,
+            day: result.day.clone(),
+            part: result.part,
+            input: result.input.to_string(),
+            elapsed_ns: result.elapsed.as_nanos() as u64,
+            variant: result.variant.clone(),
+        })?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+fn median(mut samples: Vec<u64>) -> Duration {
+    samples.sort();
+    let n = samples.len();
+    let nanos = if n.is_multiple_of(2) {
+        (samples[n / 2 - 1] + samples[n / 2]) / 2
+    } else {
+        samples[n / 2]
+    };
+    Duration::from_nanos(nanos)
+}
+
+/// Day, part, input and variant.
+type PartKey = (String, usize, String, String);
+
+/// Prints each part's median time per commit, oldest first, flagging any commit that's more
+/// than `threshold` percent slower than the one before it. Returns whether any were flagged.
+pub fn report(day: Option<&str>, threshold: f64) -> anyhow::Result<bool> {
+    let path = History::path();
+    let mut reader = match csv::Reader::from_path(&path) {
+        Ok(reader) => reader,
+        Err(e) => match e.kind() {
+            csv::ErrorKind::Io(io) if io.kind() == std::io::ErrorKind::NotFound => {
+                bail!("no history yet, run with --history to record some")
+            }
+            _ => return Err(e).with_context(|| format!("reading {}", path.display())),
+        },
+    };
+    let entries = reader
+        .deserialize::<Entry>()
+        .collect::<Result<Vec<_>, _>>()
+        .with_context(|| format!("reading {}", path.display()))?;
+
+    // keeping commits in the order they were first run
+    let mut parts: HashMap<PartKey, Vec<(String, Vec<u64>)>> = HashMap::new();
+    for entry in entries {
+        if day.is_some_and(|day| day != entry.day) {
+            continue;
+        }
+        let revisions = parts
+            .entry((entry.day, entry.part, entry.input, entry.variant))
+            .or_default();
+        let label = Revision::label(&entry.commit, entry.dirty);
+        match revisions
+            .iter_mut()
+            .find(|(revision, _)| *revision == label)
+        {
+            Some((_, timings)) => timings.push(entry.elapsed_ns),
+            None => revisions.push((label, vec![entry.elapsed_ns])),
+        }
+    }
+
+    let mut regressed = false;
+    for ((day, part, input, variant), revisions) in parts.into_iter().sorted() {
+        print!("{day} part {part}");
+        if variant != DEFAULT_VARIANT {
+            print!(" ({variant})");
+        }
+        if input != "real" {
+            print!(" [{input}]");
+        }
+        println!(":");
+
+        let mut previous: Option<Duration> = None;
+        for (revision, timings) in revisions {
+            let runs = timings.len();
+            let median = median(timings);
+            print!(
+                "    {revision:<11} {:>10}µs  ({runs} runs)",
+                median.as_micros()
+            );
+            if let Some(previous) = previous {
+                let change = (median.as_secs_f64() - previous.as_secs_f64())
+                    / previous.as_secs_f64()
+                    * 100.0;
+                print!("  {change:+.1}%");
+                if change > threshold {
+                    print!("  REGRESSION");
+                    regressed = true;
+                }
+            }
+            println!();
+            previous = Some(median);
+        }
+    }
+    Ok(regressed)
+}
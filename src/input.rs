@@ -0,0 +1,433 @@
+//! Where puzzle inputs come from: a local cache directory, filled on demand by a [`Fetcher`].
+//!
+//! The cache defaults to `input/` in the repo and can be moved with `--input-dir` or
+//! `AOC_INPUT_DIR`. Real inputs that aren't cached are downloaded once and written to the
+//! cache; sample inputs are always local since they're copied out of the puzzle text.
+//!
+//! A day can have several samples: `<day>-sample.txt` and any number of
+//! `<day>-sample-<name>.txt`. An input can also be given directly with `--input`, as a path or
+//! `-` for stdin, bypassing the cache.
+//!
+//! Days from years other than [`YEAR`] are keyed like `2024/day07`, so their inputs live in
+//! `input/2024/`.
+
+use std::{
+    fmt::Display,
+    path::{Path, PathBuf},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, bail};
+
+use crate::session::Session;
+
+/// The year whose days are directly in `src/`.
+pub const YEAR: u32 = 2025;
+
+/// The name a day is run, cached and recorded under: `day07` for [`YEAR`], or `2024/day07`.
+pub fn day_key(year: u32, day: &str) -> String {
+    if year == YEAR {
+        day.to_string()
+    } else {
+        format!("{year}/{day}")
+    }
+}
+
+/// Splits a key from [`day_key`] back into its year and day.
+pub fn split_day_key(key: &str) -> (u32, &str) {
+    match key.split_once('/') {
+        Some((year, day)) => (year.parse().unwrap_or(YEAR), day),
+        None => (YEAR, key),
+    }
+}
+
+/// Which of a day's inputs to run on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum InputKind {
+    Real,
+    /// `<day>-sample.txt`, or `<day>-sample-<name>.txt` when named.
+    Sample(Option<String>),
+    /// A file given with `--input`. `sample` is what the day is told about it.
+    File {
+        path: PathBuf,
+        sample: bool,
+    },
+    /// Standard input, with `--input -`.
+    Stdin {
+        sample: bool,
+    },
+}
+
+impl InputKind {
+    /// Whether the day should treat this as a sample, e.g. to use smaller parameters.
+    pub fn is_sample(&self) -> bool {
+        match self {
+            InputKind::Real => false,
+            InputKind::Sample(_) => true,
+            InputKind::File { sample, .. } | InputKind::Stdin { sample } => *sample,
+        }
+    }
+
+    /// Parses an `--input` argument: a path, or `-` for stdin.
+    pub fn given(arg: &Path, sample: bool) -> Self {
+        if arg == Path::new("-") {
+            InputKind::Stdin { sample }
+        } else {
+            InputKind::File {
+                path: arg.to_path_buf(),
+                sample,
+            }
+        }
+    }
+
+    fn file_stem(&self, day: &str) -> String {
+        match self {
+            InputKind::Real => day.to_string(),
+            InputKind::Sample(None) => format!("{day}-sample"),
+            InputKind::Sample(Some(name)) => format!("{day}-sample-{name}"),
+            InputKind::File { .. } | InputKind::Stdin { .. } => {
+                unreachable!("{self} isn't in the cache")
+            }
+        }
+    }
+}
+
+impl Display for InputKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputKind::Real => write!(f, "real"),
+            InputKind::Sample(None) => write!(f, "sample"),
+            InputKind::Sample(Some(name)) => write!(f, "sample-{name}"),
+            InputKind::File { path, .. } => write!(f, "{}", path.display()),
+            InputKind::Stdin { .. } => write!(f, "stdin"),
+        }
+    }
+}
+
+impl std::str::FromStr for InputKind {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        match s {
+            "real" => Ok(InputKind::Real),
+            "sample" => Ok(InputKind::Sample(None)),
+            _ => match s.strip_prefix("sample-") {
+                Some(name) if !name.is_empty() => Ok(InputKind::Sample(Some(name.to_string()))),
+                _ => bail!("unknown input {s:?}"),
+            },
+        }
+    }
+}
+
+/// How a day wants its input cleaned up before it sees it. Declared with
+/// `pub const INPUT: Normalize = ...;` in the day's module, defaulting to [`Normalize::Trim`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Normalize {
+    /// Exactly what's in the file.
+    Raw,
+    /// Only the newlines at the end, for inputs where leading or trailing spaces matter.
+    TrimNewline,
+    /// Whitespace at both ends.
+    #[default]
+    Trim,
+}
+
+impl Normalize {
+    pub fn apply(self, input: &str) -> &str {
+        match self {
+            Normalize::Raw => input,
+            Normalize::TrimNewline => input.trim_end_matches(['\r', '\n']),
+            Normalize::Trim => input.trim(),
+        }
+    }
+}
+
+/// Problems with an input's whitespace that normalization won't fix and that may trip up a
+/// day: Windows line endings, or some lines having trailing whitespace and others not.
+pub fn whitespace_warnings(input: &str) -> Vec<&'static str> {
+    let mut warnings = vec![];
+    if input.contains("\r\n") {
+        warnings.push("has CRLF line endings");
+    }
+    let trailing = input
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.ends_with([' ', '\t']))
+        .collect::<Vec<_>>();
+    if trailing.contains(&true) && trailing.contains(&false) {
+        warnings.push("has trailing whitespace on some lines but not others");
+    }
+    warnings
+}
+
+/// Which inputs each day runs on.
+pub enum Selection {
+    Real,
+    /// Every one of the day's samples.
+    Samples,
+    Given(InputKind),
+}
+
+pub trait Fetcher: Send + Sync {
+    /// Downloads the real input for a day of the given year.
+    fn fetch(&self, year: u32, day: u32) -> anyhow::Result<String>;
+}
+
+/// Fetches inputs from the site through a [`Session`].
+pub struct HttpFetcher {
+    session: Session,
+    /// Minimum time between requests, so a run over many missing days doesn't hammer the site.
+    min_interval: Duration,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl HttpFetcher {
+    pub fn new(session: Session) -> Self {
+        Self {
+            session,
+            min_interval: Duration::from_secs(5),
+            last_request: Mutex::new(None),
+        }
+    }
+
+    /// Waits until `min_interval` has passed since the previous request.
+    fn throttle(&self) {
+        let mut last_request = self.last_request.lock().unwrap();
+        if let Some(last) = *last_request {
+            let since = last.elapsed();
+            if since < self.min_interval {
+                std::thread::sleep(self.min_interval - since);
+            }
+        }
+        *last_request = Some(Instant::now());
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u32, day: u32) -> anyhow::Result<String> {
+        self.throttle();
+
+        let url = self.session.url(&format!("{year}/day/{day}/input"));
+        let response = self.session.get(&url).call();
+        match response {
+            Ok(response) => response
+                .into_string()
+                .with_context(|| format!("reading response from {url}")),
+            Err(ureq::Error::Status(404, _)) => bail!("{url}: input isn't available yet"),
+            Err(ureq::Error::Status(429, _)) => bail!("{url}: rate limited, try again later"),
+            Err(ureq::Error::Status(code @ (400 | 401 | 500), _)) => {
+                bail!("{url}: HTTP {code}, is the session cookie valid?")
+            }
+            Err(e) => Err(e).context("fetching input"),
+        }
+    }
+}
+
+pub struct Inputs {
+    dir: PathBuf,
+    fetcher: Option<Box<dyn Fetcher>>,
+    /// Standard input, read up front since it can only be read once.
+    stdin: Option<String>,
+}
+
+impl Inputs {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            fetcher: None,
+            stdin: None,
+        }
+    }
+
+    pub fn default_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("input")
+    }
+
+    pub fn with_fetcher(mut self, fetcher: impl Fetcher + 'static) -> Self {
+        self.fetcher = Some(Box::new(fetcher));
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    pub fn with_stdin(mut self) -> anyhow::Result<Self> {
+        self.stdin = Some(std::io::read_to_string(std::io::stdin()).context("reading stdin")?);
+        Ok(self)
+    }
+
+    pub fn path(&self, day: &str, kind: &InputKind) -> PathBuf {
+        match kind {
+            InputKind::File { path, .. } => path.clone(),
+            _ => self.dir.join(kind.file_stem(day)).with_extension("txt"),
+        }
+    }
+
+    pub fn select(&self, day: &str, selection: &Selection) -> Vec<InputKind> {
+        match selection {
+            Selection::Real => vec![InputKind::Real],
+            Selection::Samples => self.samples(day),
+            Selection::Given(kind) => vec![kind.clone()],
+        }
+    }
+
+    /// All of a day's sample inputs, unnamed first and then by name. If there are none, this is
+    /// just the unnamed sample so that running it reports the missing file.
+    pub fn samples(&self, day: &str) -> Vec<InputKind> {
+        let prefix = self.path(day, &InputKind::Sample(None));
+        let (Some(dir), Some(prefix)) = (prefix.parent(), prefix.file_stem()) else {
+            return vec![InputKind::Sample(None)];
+        };
+        let prefix = prefix.to_string_lossy();
+        let mut samples = std::fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| {
+                let name = entry.ok()?.file_name().into_string().ok()?;
+                let rest = name.strip_suffix(".txt")?.strip_prefix(&*prefix)?;
+                match rest.strip_prefix('-') {
+                    None if rest.is_empty() => Some(InputKind::Sample(None)),
+                    Some(name) if !name.is_empty() => {
+                        Some(InputKind::Sample(Some(name.to_string())))
+                    }
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        samples.sort();
+        if samples.is_empty() {
+            samples.push(InputKind::Sample(None));
+        }
+        samples
+    }
+
+    /// Reads a day's input from the cache, fetching and caching real inputs that are missing.
+    pub fn read(&self, day: &str, kind: &InputKind) -> anyhow::Result<String> {
+        if let InputKind::Stdin { .. } = kind {
+            return self.stdin.clone().context("stdin wasn't read");
+        }
+        let path = self.path(day, kind);
+        match std::fs::read_to_string(&path) {
+            Ok(contents) => return Ok(contents),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound && kind == &InputKind::Real => {}
+            Err(e) => return Err(e).with_context(|| format!("reading {}", path.display())),
+        }
+
+        let Some(fetcher) = &self.fetcher else {
+            bail!(
+                "{} doesn't exist, and there's no session file to fetch it with",
+                path.display()
+            );
+        };
+        let (year, name) = split_day_key(day);
+        let number = name
+            .strip_prefix("day")
+            .and_then(|number| number.parse().ok())
+            .with_context(|| format!("can't fetch input for {day}, expected a name like day07"))?;
+        let contents = fetcher.fetch(year, number)?;
+
+        let dir = path.parent().unwrap_or(&self.dir);
+        std::fs::create_dir_all(dir).with_context(|| format!("creating {}", dir.display()))?;
+        // write then rename so an interrupted download never leaves a truncated input cached
+        let partial = path.with_extension("txt.partial");
+        std::fs::write(&partial, &contents)
+            .with_context(|| format!("writing {}", partial.display()))?;
+        std::fs::rename(&partial, &path).with_context(|| format!("writing {}", path.display()))?;
+
+        Ok(contents)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use super::*;
+    use crate::session::stub;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc2025-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn test_http_fetch() {
+        let (url, server) = stub::server(vec![
+            "HTTP/1.1 200 OK\r\nContent-Length: 6\r\nConnection: close\r\n\r\n1\n2\n3\n",
+        ]);
+        let fetcher = HttpFetcher::new(Session::new(url, "abc123"));
+        assert_eq!(fetcher.fetch(2025, 7).unwrap(), "1\n2\n3\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2025/day/7/input "));
+        assert!(requests[0].contains("Cookie: session=abc123\r\n"));
+    }
+
+    #[test]
+    fn test_http_fetch_errors() {
+        let (url, server) = stub::server(vec![
+            "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+            "HTTP/1.1 500 Internal Server Error\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+        ]);
+        let mut fetcher = HttpFetcher::new(Session::new(url, "abc123"));
+        fetcher.min_interval = Duration::ZERO;
+        let not_found = fetcher.fetch(2025, 25).unwrap_err().to_string();
+        assert!(not_found.contains("isn't available yet"), "{not_found}");
+        let bad_session = fetcher.fetch(2025, 1).unwrap_err().to_string();
+        assert!(bad_session.contains("session"), "{bad_session}");
+        server.join().unwrap();
+    }
+
+    struct CountingFetcher(Arc<AtomicUsize>);
+
+    impl Fetcher for CountingFetcher {
+        fn fetch(&self, _year: u32, day: u32) -> anyhow::Result<String> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(format!("input for {day}"))
+        }
+    }
+
+    #[test]
+    fn test_fetches_once_and_caches() {
+        let dir = temp_dir("cache");
+        let fetches = Arc::new(AtomicUsize::new(0));
+        let inputs = Inputs::new(&dir).with_fetcher(CountingFetcher(fetches.clone()));
+
+        assert_eq!(
+            inputs.read("day03", &InputKind::Real).unwrap(),
+            "input for 3"
+        );
+        assert_eq!(
+            inputs.read("day03", &InputKind::Real).unwrap(),
+            "input for 3"
+        );
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+        assert_eq!(
+            std::fs::read_to_string(dir.join("day03.txt")).unwrap(),
+            "input for 3"
+        );
+
+        // samples are never fetched
+        assert!(inputs.read("day03", &InputKind::Sample(None)).is_err());
+        assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_normalize() {
+        let input = "  1\n 23\n\n";
+        assert_eq!(Normalize::Raw.apply(input), input);
+        assert_eq!(Normalize::TrimNewline.apply(input), "  1\n 23");
+        assert_eq!(Normalize::Trim.apply(input), "1\n 23");
+
+        assert!(whitespace_warnings("a \nb \n").is_empty());
+        assert_eq!(whitespace_warnings("a\r\nb \n").len(), 2);
+    }
+}
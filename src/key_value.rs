@@ -0,0 +1,75 @@
+//! The `key = value` files that `params.txt` and `answers/` are written in.
+
+use std::path::Path;
+
+use anyhow::{Context, bail};
+
+/// A line of a `key = value` file. Blank lines and `#` comments are kept so a file can be
+/// written back without losing them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Line {
+    Entry(String, String),
+    Other(String),
+}
+
+impl Line {
+    pub fn entry(&self) -> Option<(&str, &str)> {
+        match self {
+            Line::Entry(key, value) => Some((key, value)),
+            Line::Other(_) => None,
+        }
+    }
+}
+
+/// Reads every line of a `key = value` file in order. A missing file is treated as empty.
+pub fn read_lines(path: &Path) -> anyhow::Result<Vec<Line>> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e).with_context(|| format!("reading {}", path.display())),
+    };
+
+    let mut lines = Vec::new();
+    for (number, raw) in contents.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            lines.push(Line::Other(raw.to_string()));
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            bail!("{}:{}: expected `key = value`", path.display(), number + 1);
+        };
+        lines.push(Line::Entry(
+            key.trim().to_string(),
+            value.trim().to_string(),
+        ));
+    }
+    Ok(lines)
+}
+
+/// Reads just the entries of a `key = value` file in order.
+pub fn read(path: &Path) -> anyhow::Result<Vec<(String, String)>> {
+    Ok(read_lines(path)?
+        .into_iter()
+        .filter_map(|line| match line {
+            Line::Entry(key, value) => Some((key, value)),
+            Line::Other(_) => None,
+        })
+        .collect())
+}
+
+/// Writes lines back out, entries as `key = value`.
+pub fn write(path: &Path, lines: &[Line]) -> anyhow::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("creating {}", parent.display()))?;
+    }
+    let contents: String = lines
+        .iter()
+        .map(|line| match line {
+            Line::Entry(key, value) => format!("{key} = {value}\n"),
+            Line::Other(raw) => format!("{raw}\n"),
+        })
+        .collect();
+    std::fs::write(path, contents).with_context(|| format!("writing {}", path.display()))
+}
@@ -0,0 +1,39 @@
+//! The toolkit shared by every day: grids, graph searches and the prelude days import, along
+//! with the input, parameter, tracing and cancellation support the prelude builds on.
+//!
+//! Kept free of nightly features so it builds on stable; those stay in the binary with the
+//! days that need them.
+
+pub mod prelude {
+    pub use crate::cancel::check_cancelled;
+    pub use crate::convert::CheckedInto;
+    pub use crate::graph;
+    pub use crate::grid::*;
+    pub use crate::input::Normalize;
+    pub use crate::params::Params;
+    pub use crate::trace::span;
+    pub use ::anyhow::{self, Context, anyhow, bail, ensure};
+    pub use bitvec;
+    pub use euclid::{default::*, point2, vec2};
+    pub use hashbag::HashBag;
+    pub use itertools::Itertools;
+    pub use log;
+    pub use num::{Float, Integer};
+    pub use rand::Rng;
+    pub use rand_chacha::ChaCha8Rng;
+    pub use rayon::prelude::*;
+    pub use regex;
+    pub use regex::Regex;
+    pub use std::collections::HashMap;
+}
+
+pub mod cancel;
+pub mod convert;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod key_value;
+pub mod params;
+pub mod session;
+pub mod submission;
+pub mod trace;
@@ -0,0 +1,121 @@
+//! Routes log output from days away from the results on stdout.
+//!
+//! Records logged from a `dayNN` module go to stderr prefixed with the day, or to
+//! `<log dir>/<day>.log` with `--log-dir`. Days log warnings and errors by default, and debug
+//! output too for days named with `--verbose`. Everything else goes through `env_logger` as
+//! before, so `RUST_LOG` still applies.
+
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::Write,
+    path::PathBuf,
+    sync::Mutex,
+};
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+
+use crate::input;
+
+pub struct DayLogger {
+    verbose: HashSet<String>,
+    log_dir: Option<PathBuf>,
+    files: Mutex<HashMap<String, File>>,
+    fallback: env_logger::Logger,
+}
+
+/// The day a log target like `aoc2025::day09` or `aoc2025::y2024::day09` belongs to, as an
+/// [`input::day_key`].
+fn day_of(target: &str) -> Option<String> {
+    let segments = target.split("::").collect::<Vec<_>>();
+    let index = segments.iter().position(|segment| {
+        segment
+            .strip_prefix("day")
+            .is_some_and(|number| number.len() == 2 && number.bytes().all(|b| b.is_ascii_digit()))
+    })?;
+    let year = index
+        .checked_sub(1)
+        .and_then(|previous| segments[previous].strip_prefix('y')?.parse().ok())
+        .unwrap_or(input::YEAR);
+    Some(input::day_key(year, segments[index]))
+}
+
+impl DayLogger {
+    pub fn init(verbose: &[String], log_dir: Option<PathBuf>) -> anyhow::Result<()> {
+        let fallback = env_logger::Builder::from_default_env().build();
+        let day_level = if verbose.is_empty() {
+            LevelFilter::Warn
+        } else {
+            LevelFilter::Debug
+        };
+        log::set_max_level(fallback.filter().max(day_level));
+        log::set_boxed_logger(Box::new(Self {
+            verbose: verbose.iter().cloned().collect(),
+            log_dir,
+            files: Mutex::new(HashMap::new()),
+            fallback,
+        }))?;
+        Ok(())
+    }
+
+    fn day_enabled(&self, day: &str, metadata: &Metadata) -> bool {
+        let level = if self.verbose.contains(day) {
+            Level::Debug
+        } else {
+            Level::Warn
+        };
+        metadata.level() <= level || self.fallback.enabled(metadata)
+    }
+
+    fn write_to_file(&self, day: &str, record: &Record) -> std::io::Result<()> {
+        let dir = self.log_dir.as_ref().unwrap();
+        let mut files = self.files.lock().unwrap();
+        if !files.contains_key(day) {
+            std::fs::create_dir_all(dir)?;
+            // truncated on the first record of each run
+            let file = File::create(dir.join(day).with_extension("log"))?;
+            files.insert(day.to_string(), file);
+        }
+        writeln!(
+            files.get_mut(day).unwrap(),
+            "{} {}",
+            record.level(),
+            record.args()
+        )
+    }
+}
+
+impl Log for DayLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        match day_of(metadata.target()) {
+            Some(day) => self.day_enabled(&day, metadata),
+            None => self.fallback.enabled(metadata),
+        }
+    }
+
+    fn log(&self, record: &Record) {
+        let Some(day) = day_of(record.target()) else {
+            self.fallback.log(record);
+            return;
+        };
+        if !self.day_enabled(&day, record.metadata()) {
+            return;
+        }
+        if self.log_dir.is_none() {
+            eprintln!("[{day} {}] {}", record.level(), record.args());
+        } else if let Err(e) = self.write_to_file(&day, record) {
+            eprintln!(
+                "[{day} {}] {} (writing log: {e})",
+                record.level(),
+                record.args()
+            );
+        }
+    }
+
+    fn flush(&self) {
+        for file in self.files.lock().unwrap().values_mut() {
+            let _ = file.flush();
+        }
+        self.fallback.flush();
+    }
+}
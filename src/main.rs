@@ -1,160 +1,533 @@
 #![feature(hash_set_entry)]
 #![feature(ascii_char)]
-use std::{fmt::Display, path::Path};
-
-use clap::Parser;
-
-pub mod prelude {
-    pub use super::graph;
-    pub use super::grid::*;
-    pub use bitvec;
-    pub use euclid::{default::*, point2, vec2};
-    pub use hashbag::HashBag;
-    pub use itertools::Itertools;
-    pub use num::{Float, Integer};
-    pub use rayon::prelude::*;
-    pub use regex;
-    pub use regex::Regex;
-    pub use std::collections::HashMap;
-}
-
-pub mod graph;
-pub mod grid;
+use std::{
+    ffi::OsString,
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
-// Inspired by https://git.sr.ht/~gadanidis/aoc2024/tree/main/item/src/main.rs
+use anyhow::Context;
+use clap::{
+    CommandFactory, FromArgMatches, Parser, Subcommand, error::ErrorKind, parser::ValueSource,
+};
 
-type DayFn = Box<dyn Fn(&str, bool) -> String + Send + Sync + 'static>;
-
-struct Runner {
-    days: Vec<(String, (DayFn, DayFn))>,
-}
-impl Runner {
-    fn new() -> Self {
-        Self { days: Vec::new() }
-    }
-
-    fn register_day<T1, T2, F1, F2>(&mut self, name: &str, part1: F1, part2: F2)
-    where
-        F1: Fn(&str, bool) -> T1,
-        F1: Send + Sync + 'static,
-        T1: Display,
-        F2: Fn(&str, bool) -> T2,
-        F2: Send + Sync + 'static,
-        T2: Display,
-    {
-        self.days.push((
-            name.to_string(),
-            (
-                Box::new(move |input, sample| part1(input, sample).to_string()),
-                Box::new(move |input, sample| part2(input, sample).to_string()),
-            ),
-        ));
-    }
+use crate::{
+    bench::Baseline,
+    history::History,
+    input::{HttpFetcher, InputKind, Inputs, Selection},
+    logging::DayLogger,
+    output::{Format, Reporter},
+    params::ParamConfig,
+    runner::{DEFAULT_VARIANT, Runner, Status},
+    session::Session,
+    submission::HttpSubmitter,
+};
 
-    fn run(&self, day: &str, part: usize, sample: bool) {
-        let (part1, part2) = &self
-            .days
-            .iter()
-            .find(|(name, _)| name == day)
-            .expect("Day not found")
-            .1;
-
-        let contents = std::fs::read_to_string(
-            Path::new(env!("CARGO_MANIFEST_DIR"))
-                .join("input")
-                .join(if sample {
-                    format!("{}-sample", day)
-                } else {
-                    day.to_string()
-                })
-                .with_extension("txt"),
-        )
-        .expect("Failed to read input");
-        let input = contents.trim();
-
-        let start = std::time::Instant::now();
-        let result = match part {
-            1 => part1(input, sample),
-            2 => part2(input, sample),
-            _ => panic!("Invalid part {}", part),
-        };
-        let elapsed = start.elapsed();
-        println!(
-            "{} part {}: {}\t({}µs)",
-            day,
-            part,
-            result,
-            elapsed.as_micros()
-        );
-    }
+pub use aoc2025::{
+    cancel, graph, grid, input, key_value, params, prelude, session, submission, trace,
+};
 
-    fn run_all(&self) {
-        for (day, _) in &self.days {
-            self.run(day, 1, false);
-            self.run(day, 2, false);
-        }
-    }
-}
+mod alloc;
+mod answers;
+mod bench;
+mod fuzz;
+mod history;
+mod logging;
+mod output;
+mod runner;
+mod scaffold;
+mod submit;
+mod watch;
 
 #[derive(Parser)]
 struct Args {
+    #[clap(subcommand)]
+    command: Option<Command>,
+
     /// Day to run (default all)
     day: Option<String>,
 
+    /// Year the day is from
+    #[clap(long, env = "AOC_YEAR", default_value_t = input::YEAR)]
+    year: u32,
+
+    /// Run every day of every year, rather than just --year
+    #[clap(long, conflicts_with = "day")]
+    all_years: bool,
+
     /// Part to run (1 or 2) (default both)
     #[clap(long, short)]
     part: Option<usize>,
 
-    /// Use sample data
+    /// Use sample data, running every input/<day>-sample*.txt. With --input, just tells the day
+    /// that its input is a sample
     #[clap(long, short)]
     sample: bool,
+
+    /// Read the day's input from this file, or stdin for `-`, instead of the input directory
+    #[clap(long, conflicts_with_all = ["record", "save_baseline", "baseline"])]
+    input: Option<PathBuf>,
+
+    /// Which variant of each part to run, or `all` to cross-check every variant
+    #[clap(long, default_value = DEFAULT_VARIANT)]
+    variant: String,
+
+    /// Write answers for NEW results into answers/
+    #[clap(long)]
+    record: bool,
+
+    /// Number of days to run concurrently
+    #[clap(long, short, default_value_t = 1)]
+    jobs: usize,
+
+    /// With --jobs, only time one part at a time so timings aren't skewed by other days
+    #[clap(long)]
+    serial_timing: bool,
+
+    /// Show debug logs from this day, e.g. --verbose day09 (can be repeated)
+    #[clap(long, value_name = "DAY")]
+    verbose: Vec<String>,
+
+    /// Write each day's logs to <dir>/<day>.log instead of stderr
+    #[clap(long, value_name = "DIR")]
+    log_dir: Option<PathBuf>,
+
+    /// Append each part's timing to history.csv, tagged with the current git commit
+    #[clap(long)]
+    history: bool,
+
+    /// Show how long each part spent in its spans
+    #[clap(long)]
+    trace: bool,
+
+    /// Give up on a part after this many seconds and report it as TIMEOUT
+    #[clap(long)]
+    timeout: Option<f64>,
+
+    /// Override a puzzle parameter from params.txt, e.g. --param joins=50
+    #[clap(long = "param", value_name = "NAME=VALUE", value_parser = params::parse_override)]
+    params: Vec<(String, String)>,
+
+    /// Directory of cached inputs
+    #[clap(long, env = "AOC_INPUT_DIR", global = true)]
+    input_dir: Option<PathBuf>,
+
+    /// File containing the adventofcode.com session cookie, used to fetch missing inputs
+    #[clap(long, env = "AOC_SESSION_FILE", global = true)]
+    session_file: Option<PathBuf>,
+
+    /// Format for printing results
+    #[clap(long, value_enum, default_value_t)]
+    output: Format,
+
+    /// Time each part repeatedly and report statistics instead of a single run
+    #[clap(long, conflicts_with_all = ["output", "history", "record"])]
+    bench: bool,
+
+    /// Untimed runs of each part before measuring (with --bench)
+    #[clap(long, default_value_t = 3)]
+    warmup: usize,
+
+    /// Timed runs of each part (with --bench)
+    #[clap(long, default_value_t = 50)]
+    iterations: usize,
+
+    /// Save benchmark statistics under this baseline name (with --bench)
+    #[clap(long)]
+    save_baseline: Option<String>,
+
+    /// Compare benchmark statistics against this saved baseline (with --bench)
+    #[clap(long)]
+    baseline: Option<String>,
 }
 
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
+#[derive(Subcommand)]
+enum Command {
+    /// Create src/<day>.rs from the template and an empty sample input for it
+    New {
+        /// Day to create, e.g. day13 or 13
+        day: String,
 
-pub fn main() {
-    env_logger::init();
+        /// Year the day is from
+        #[clap(long, env = "AOC_YEAR", default_value_t = input::YEAR)]
+        year: u32,
+    },
+    /// Rebuild and re-run a day whenever its source or inputs change
+    Watch {
+        /// Day to watch, e.g. day07
+        day: String,
+
+        /// Year the day is from
+        #[clap(long, env = "AOC_YEAR", default_value_t = input::YEAR)]
+        year: u32,
+
+        /// Part to run (1 or 2) (default both)
+        #[clap(long, short)]
+        part: Option<usize>,
+
+        /// Use sample data
+        #[clap(long, short)]
+        sample: bool,
+    },
+    /// Run a part on the real input and submit its answer to adventofcode.com, recording
+    /// whether it was right
+    Submit {
+        /// Day to submit, e.g. day07
+        day: String,
+
+        /// Part to submit (1 or 2)
+        part: usize,
 
+        /// Year the day is from
+        #[clap(long, env = "AOC_YEAR", default_value_t = input::YEAR)]
+        year: u32,
+    },
+    /// Check a day's parts against their reference variants on random inputs from its
+    /// generator, writing the first input they disagree on as the `fuzz` sample
+    Fuzz {
+        /// Day to fuzz, e.g. day07
+        day: String,
+
+        /// Year the day is from
+        #[clap(long, env = "AOC_YEAR", default_value_t = input::YEAR)]
+        year: u32,
+
+        /// Part to check (1 or 2) (default both)
+        #[clap(long, short)]
+        part: Option<usize>,
+
+        /// Number of random inputs to try
+        #[clap(long, default_value_t = 1000)]
+        iterations: u64,
+
+        /// Seed for the first input, each later one using the next seed
+        #[clap(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Show how each part's timing changed across commits in history.csv, exiting with an
+    /// error if any got slower than the threshold
+    Report {
+        /// Only report this day, e.g. day07 or 2024/day07
+        day: Option<String>,
+
+        /// Percentage slowdown from one commit to the next that counts as a regression
+        #[clap(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+}
+
+impl Args {
+    /// Parses the arguments, rejecting options for running days when a subcommand is given.
+    /// Clap's `args_conflicts_with_subcommands` would count the global options too, so
+    /// `--input-dir <dir> fuzz day01` would take `fuzz` as the day to run.
+    fn try_parse_checked(
+        args: impl IntoIterator<Item = impl Into<OsString> + Clone>,
+    ) -> Result<Self, clap::Error> {
+        let mut command = Self::command();
+        let matches = command.try_get_matches_from_mut(args)?;
+        if let Some((name, _)) = matches.subcommand() {
+            let conflicting = command.get_arguments().find(|arg| {
+                let id = arg.get_id().as_str();
+                !arg.is_global_set()
+                    && matches.try_contains_id(id).is_ok()
+                    && matches.value_source(id) == Some(ValueSource::CommandLine)
+            });
+            if let Some(arg) = conflicting {
+                let arg = arg.get_long().map_or_else(
+                    || format!("[{}]", arg.get_id().as_str().to_uppercase()),
+                    |long| format!("--{long}"),
+                );
+                return Err(command.error(
+                    ErrorKind::ArgumentConflict,
+                    format!("{arg} can't be used with the {name} subcommand"),
+                ));
+            }
+        }
+        Self::from_arg_matches(&matches)
+    }
+}
+
+fn inputs(args: &Args) -> anyhow::Result<Inputs> {
+    let mut inputs = Inputs::new(args.input_dir.clone().unwrap_or_else(Inputs::default_dir));
+    if args.input.as_deref() == Some(Path::new("-")) {
+        inputs = inputs.with_stdin()?;
+    }
+    Ok(match session_file(args) {
+        Some(path) => inputs.with_fetcher(HttpFetcher::new(Session::from_file(&path)?)),
+        None => inputs,
+    })
+}
+
+/// The session cookie file given with `--session-file`, or `.session` in the repo if it exists.
+fn session_file(args: &Args) -> Option<PathBuf> {
+    let default_session = Path::new(env!("CARGO_MANIFEST_DIR")).join(".session");
+    args.session_file
+        .clone()
+        .or_else(|| default_session.exists().then_some(default_session))
+}
+
+fn submit(args: &Args, runner: &mut Runner, day: &str, part: usize) -> anyhow::Result<()> {
+    let session = session_file(args).context(
+        "submitting needs a session cookie in .session, AOC_SESSION_FILE or \
+             `submit <day> <part> --session-file <file>`",
+    )?;
+    let submitter = HttpSubmitter::new(Session::from_file(&session)?);
+    configure(args, runner)?;
+    submit::submit(runner, &submitter, day, part)
+}
+
+/// Gives a runner the inputs and parameters, for subcommands that run days.
+fn configure(args: &Args, runner: &mut Runner) -> anyhow::Result<()> {
+    runner.inputs = inputs(args)?;
+    runner.params = ParamConfig::load()?;
+    Ok(())
+}
+
+// `mod dayNN;` for every src/dayNN.rs and src/yYYYY/dayNN.rs, plus `register_days`; see build.rs
+include!(concat!(env!("OUT_DIR"), "/days.rs"));
+
+pub fn main() {
     let mut runner = Runner::new();
-    runner.register_day("day01", day01::part1, day01::part2);
-    runner.register_day("day02", day02::part1, day02::part2);
-    runner.register_day("day03", day03::part1, day03::part2);
-    runner.register_day("day04", day04::part1, day04::part2);
-    runner.register_day("day05", day05::part1, day05::part2);
-    runner.register_day("day06", day06::part1, day06::part2);
-    runner.register_day("day07", day07::part1, day07::part2);
-    runner.register_day("day08", day08::part1, day08::part2);
-    runner.register_day("day09", day09::part1, day09::part2);
-    runner.register_day("day10", day10::part1, day10::part2);
-    runner.register_day("day11", day11::part1, day11::part2);
-    runner.register_day("day12", day12::part1, day12::part2);
-
-    let args = Args::parse();
+    register_days(&mut runner);
+
+    let args = Args::try_parse_checked(std::env::args_os()).unwrap_or_else(|e| e.exit());
+    DayLogger::init(&args.verbose, args.log_dir.clone()).expect("Failed to set up logging");
+
+    if let Some(command) = &args.command {
+        let result = match command {
+            Command::New { day, year } => {
+                inputs(&args).and_then(|inputs| scaffold::new_day(*year, day, &inputs))
+            }
+            Command::Watch {
+                day,
+                year,
+                part,
+                sample,
+            } => inputs(&args).and_then(|inputs| {
+                watch::Watch {
+                    year: *year,
+                    day: day.clone(),
+                    part: *part,
+                    sample: *sample,
+                    inputs,
+                    session_file: session_file(&args),
+                }
+                .run()
+            }),
+            Command::Submit { day, part, year } => {
+                submit(&args, &mut runner, &input::day_key(*year, day), *part)
+            }
+            Command::Fuzz {
+                day,
+                year,
+                part,
+                iterations,
+                seed,
+            } => {
+                let parts = part.map_or(vec![1, 2], |part| vec![part]);
+                let day = input::day_key(*year, day);
+                configure(&args, &mut runner)
+                    .and_then(|()| fuzz::fuzz(&runner, &day, &parts, *iterations, *seed))
+                    .map(|failed| {
+                        if failed {
+                            std::process::exit(1);
+                        }
+                    })
+            }
+            Command::Report { day, threshold } => {
+                history::report(day.as_deref(), *threshold).map(|regressed| {
+                    if regressed {
+                        std::process::exit(1);
+                    }
+                })
+            }
+        };
+        if let Err(e) = result {
+            eprintln!("{e:#}");
+            std::process::exit(1);
+        }
+        return;
+    }
 
     if args.sample && args.day.is_none() {
         eprintln!("--sample requires --day");
         std::process::exit(1);
     }
+    if args.input.is_some() && args.day.is_none() {
+        eprintln!("--input requires --day");
+        std::process::exit(1);
+    }
+    let selection = match &args.input {
+        Some(path) => Selection::Given(InputKind::given(path, args.sample)),
+        None if args.sample => Selection::Samples,
+        None => Selection::Real,
+    };
+
+    let years = if args.all_years {
+        runner.years()
+    } else {
+        vec![args.year]
+    };
+    let days = match &args.day {
+        Some(day) => vec![input::day_key(args.year, day)],
+        None => years
+            .into_iter()
+            .flat_map(|year| {
+                runner
+                    .day_names(year)
+                    .map(str::to_string)
+                    .collect::<Vec<_>>()
+            })
+            .collect(),
+    };
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    runner.serial_timing = args.serial_timing;
+    runner.timeout = args.timeout.map(Duration::from_secs_f64);
+    runner.trace = args.trace;
+    runner.variant = args.variant.clone();
+    let selected = days.iter().any(|day| {
+        parts
+            .iter()
+            .any(|&part| !runner.selected_variants(day, part).is_empty())
+    });
+    if !days.is_empty() && !selected {
+        eprintln!("no day being run has a variant named {}", args.variant);
+        std::process::exit(1);
+    }
+    runner.inputs = inputs(&args).unwrap_or_else(|e| {
+        eprintln!("{e:#}");
+        std::process::exit(1);
+    });
+    runner.params = ParamConfig::load().unwrap_or_else(|e| {
+        eprintln!("{e:#}");
+        std::process::exit(1);
+    });
+    runner.params.overrides = args.params.clone();
 
-    match args.day {
-        Some(day) => match args.part {
-            Some(part) => runner.run(&day, part, args.sample),
-            None => {
-                runner.run(&day, 1, args.sample);
-                runner.run(&day, 2, args.sample);
+    if args.bench {
+        let load = |name| {
+            Baseline::load(name).unwrap_or_else(|e| {
+                eprintln!("{e:#}");
+                std::process::exit(1);
+            })
+        };
+        let baseline = args.baseline.as_deref().map(load);
+        let mut saved = args.save_baseline.as_deref().map(load);
+
+        for day in &days {
+            let kinds = runner.inputs.select(day, &selection);
+            for &part in &parts {
+                for kind in &kinds {
+                    for variant in runner.selected_variants(day, part) {
+                        let result =
+                            runner.bench(day, part, &variant, kind, args.warmup, args.iterations);
+                        let mut result = match result {
+                            Ok(result) => result,
+                            Err(e) => {
+                                println!("{day} part {part} ({variant}, {kind}): ERROR: {e:#}");
+                                continue;
+                            }
+                        };
+                        result.baseline = baseline
+                            .as_ref()
+                            .and_then(|baseline| baseline.get(day, part, kind, &variant));
+                        println!("{}", result);
+                        if let Some(saved) = &mut saved {
+                            saved.set(&result);
+                        }
+                    }
+                }
+            }
+        }
+
+        if let Some(Err(e)) = saved.map(|saved| saved.save()) {
+            eprintln!("{e:#}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let mut history = args.history.then(|| {
+        History::open().unwrap_or_else(|e| {
+            eprintln!("{e:#}");
+            std::process::exit(1);
+        })
+    });
+    let mut reporter = Reporter::new(args.output);
+    let mut failed = Vec::new();
+    runner.run_all(&days, &parts, &selection, args.jobs, |result| {
+        reporter.report(&result).expect("Failed to write result");
+        if let Some(history) = &mut history {
+            history.append(&result).expect("Failed to write history");
+        }
+        match result.status {
+            Status::Fail { .. }
+            | Status::Disagree { .. }
+            | Status::Error(_)
+            | Status::Timeout(_) => failed.push(result.label()),
+            Status::New if args.record => {
+                runner::record(&result).expect("Failed to record answer");
             }
-        },
-        None => runner.run_all(),
+            _ => {}
+        }
+    });
+
+    if !failed.is_empty() {
+        eprintln!("failed: {}", failed.join(", "));
+        std::process::exit(1);
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_global_options_around_subcommand() {
+        for args in [
+            ["aoc2025", "--input-dir", "/tmp/x", "fuzz", "day01"],
+            ["aoc2025", "fuzz", "day01", "--input-dir", "/tmp/x"],
+        ] {
+            let args = Args::try_parse_from(args).unwrap();
+            assert!(matches!(&args.command, Some(Command::Fuzz { day, .. }) if day == "day01"));
+            assert_eq!(args.input_dir, Some(PathBuf::from("/tmp/x")));
+            assert_eq!(args.day, None);
+        }
+    }
+
+    #[test]
+    fn test_run_options_conflict_with_subcommand() {
+        assert!(
+            Args::try_parse_checked(["aoc2025", "--session-file", "s", "submit", "day01", "1"])
+                .is_ok()
+        );
+        assert!(Args::try_parse_checked(["aoc2025", "--part", "1", "fuzz", "day01"]).is_err());
+        assert!(Args::try_parse_checked(["aoc2025", "day02", "fuzz", "day01"]).is_err());
+        assert!(Args::try_parse_checked(["aoc2025", "day02", "--part", "1"]).is_ok());
+        assert!(Args::try_parse_checked(["aoc2025", "--bench", "--output", "json"]).is_err());
+    }
+}
+
+#[cfg(test)]
+mod day_tests {
+    use super::*;
+
+    /// Runs every variant of a part on one input and checks them against the recorded answer.
+    fn check(day: &str, part: usize, input: &str) {
+        let mut runner = Runner::new();
+        register_days(&mut runner);
+        runner.params = ParamConfig::load().expect("Failed to read params.txt");
+
+        for variant in runner.variant_names(day, part).unwrap() {
+            let result = runner.run(day, part, &variant, &input.parse().unwrap());
+            assert_eq!(result.status, Status::Pass, "{result}");
+        }
+    }
+
+    // a test per recorded answer; see build.rs
+    include!(concat!(env!("OUT_DIR"), "/day_tests.rs"));
+}
@@ -0,0 +1,192 @@
+//! Formats for printing run results: human-readable text, or one machine-readable
+//! record per result as JSON lines or CSV.
+
+use std::{io::Stdout, time::Duration};
+
+use anyhow::{Context, bail};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    alloc::AllocStats,
+    runner::{RunResult, Status},
+};
+
+#[derive(Debug, Clone, Copy, Default, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+/// A result as printed by `--output json` or `csv`, and read back by `watch`.
+#[derive(Serialize, Deserialize)]
+pub struct Record {
+    day: String,
+    part: usize,
+    variant: String,
+    input: String,
+    answer: Option<String>,
+    elapsed_ns: u64,
+    allocations: Option<usize>,
+    alloc_bytes: Option<usize>,
+    peak_bytes: Option<usize>,
+    status: String,
+    /// The recorded answer for FAIL, or the other variant's answer for DISAGREE.
+    expected: Option<String>,
+    /// The variant that gave a different answer, for DISAGREE.
+    disagrees_with: Option<String>,
+    /// The `--timeout` that expired, for TIMEOUT.
+    time_limit_ns: Option<u64>,
+    error: Option<String>,
+}
+
+impl From<&RunResult> for Record {
+    fn from(result: &RunResult) -> Self {
+        let (status, expected, disagrees_with, time_limit, error) = match &result.status {
+            Status::Pass => ("PASS", None, None, None, None),
+            Status::Fail { expected } => ("FAIL", Some(expected), None, None, None),
+            Status::New => ("NEW", None, None, None, None),
+            Status::Disagree { variant, answer } => {
+                ("DISAGREE", Some(answer), Some(variant), None, None)
+            }
+            Status::Error(message) => ("ERROR", None, None, None, Some(message)),
+            Status::Timeout(limit) => ("TIMEOUT", None, None, Some(*limit), None),
+        };
+        Record {
+            day: result.day.clone(),
+            part: result.part,
+            variant: result.variant.clone(),
+            input: result.input.to_string(),
+            answer: result.answer.clone(),
+            elapsed_ns: result.elapsed.as_nanos() as u64,
+            allocations: result.alloc.map(|alloc| alloc.allocations),
+            alloc_bytes: result.alloc.map(|alloc| alloc.bytes),
+            peak_bytes: result.alloc.map(|alloc| alloc.peak),
+            status: status.to_string(),
+            expected: expected.cloned(),
+            disagrees_with: disagrees_with.cloned(),
+            time_limit_ns: time_limit.map(|limit| limit.as_nanos() as u64),
+            error: error.cloned(),
+        }
+    }
+}
+
+impl TryFrom<Record> for RunResult {
+    type Error = anyhow::Error;
+
+    fn try_from(record: Record) -> anyhow::Result<Self> {
+        let missing = |field| format!("{} result without {field}", record.status);
+        let status = match record.status.as_str() {
+            "PASS" => Status::Pass,
+            "FAIL" => Status::Fail {
+                expected: record
+                    .expected
+                    .clone()
+                    .with_context(|| missing("expected"))?,
+            },
+            "NEW" => Status::New,
+            "DISAGREE" => Status::Disagree {
+                variant: record
+                    .disagrees_with
+                    .clone()
+                    .with_context(|| missing("disagrees_with"))?,
+                answer: record
+                    .expected
+                    .clone()
+                    .with_context(|| missing("expected"))?,
+            },
+            "ERROR" => Status::Error(record.error.clone().with_context(|| missing("error"))?),
+            "TIMEOUT" => Status::Timeout(Duration::from_nanos(
+                record
+                    .time_limit_ns
+                    .with_context(|| missing("time_limit_ns"))?,
+            )),
+            status => bail!("unknown status {status:?}"),
+        };
+        let alloc = match (record.allocations, record.alloc_bytes, record.peak_bytes) {
+            (Some(allocations), Some(bytes), Some(peak)) => Some(AllocStats {
+                allocations,
+                bytes,
+                peak,
+            }),
+            _ => None,
+        };
+        Ok(RunResult {
+            day: record.day,
+            part: record.part,
+            variant: record.variant,
+            input: record.input.parse()?,
+            answer: record.answer,
+            elapsed: Duration::from_nanos(record.elapsed_ns),
+            relative: None,
+            alloc,
+            phases: None,
+            status,
+        })
+    }
+}
+
+pub struct Reporter {
+    format: Format,
+    csv: Option<csv::Writer<Stdout>>,
+}
+
+impl Reporter {
+    pub fn new(format: Format) -> Self {
+        let csv =
+            matches!(format, Format::Csv).then(|| csv::Writer::from_writer(std::io::stdout()));
+        Self { format, csv }
+    }
+
+    pub fn report(&mut self, result: &RunResult) -> anyhow::Result<()> {
+        match self.format {
+            Format::Text => println!("{}", result),
+            Format::Json => println!("{}", serde_json::to_string(&Record::from(result))?),
+            Format::Csv => {
+                let writer = self.csv.as_mut().unwrap();
+                writer.serialize(Record::from(result))?;
+                // flush per record so output can be piped while a long run continues
+                writer.flush()?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::input::InputKind;
+
+    #[test]
+    fn test_record_round_trip() {
+        for status in [
+            Status::Fail {
+                expected: "7".to_string(),
+            },
+            Status::Disagree {
+                variant: "fast".to_string(),
+                answer: "8".to_string(),
+            },
+            Status::Timeout(Duration::from_secs(2)),
+        ] {
+            let result = RunResult {
+                day: "day01".to_string(),
+                part: 2,
+                variant: "default".to_string(),
+                input: InputKind::Sample(Some("big".to_string())),
+                answer: Some("6".to_string()),
+                elapsed: Duration::from_micros(15),
+                relative: None,
+                alloc: None,
+                phases: None,
+                status,
+            };
+            let json = serde_json::to_string(&Record::from(&result)).unwrap();
+            let parsed = RunResult::try_from(serde_json::from_str::<Record>(&json).unwrap());
+            assert_eq!(parsed.unwrap().to_string(), result.to_string());
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! Named puzzle parameters, for constants that differ between inputs (like how many pairs
+//! day08 joins) so they're explicit rather than hidden behind whether the input is a sample.
+//!
+//! Defaults live in `params.txt`, one `<day>.<name> = <value>` per line. A key like
+//! `<day>.sample.<name>` or `<day>.sample-<sample name>.<name>` overrides the default for
+//! samples, and `--param <name>=<value>` overrides everything for the days being run.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use anyhow::{Context, bail};
+
+use crate::{input::InputKind, key_value};
+
+/// What a part is told about the input it's solving.
+#[derive(Debug, Clone, Default)]
+pub struct Params {
+    sample: bool,
+    values: HashMap<String, String>,
+}
+
+impl Params {
+    pub fn is_sample(&self) -> bool {
+        self.sample
+    }
+
+    pub fn get<T>(&self, name: &str) -> anyhow::Result<T>
+    where
+        T: FromStr,
+        T::Err: std::error::Error + Send + Sync + 'static,
+    {
+        let Some(value) = self.values.get(name) else {
+            bail!("missing parameter {name}, set it in params.txt or with --param {name}=...");
+        };
+        value
+            .parse()
+            .with_context(|| format!("parameter {name} = {value:?}"))
+    }
+}
+
+#[derive(Default)]
+pub struct ParamConfig {
+    entries: Vec<(String, String)>,
+    pub overrides: Vec<(String, String)>,
+}
+
+impl ParamConfig {
+    pub fn path() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("params.txt")
+    }
+
+    /// Loads `params.txt`. A missing file is treated as having no parameters.
+    pub fn load() -> anyhow::Result<Self> {
+        let path = Self::path();
+        let entries = key_value::read(&path)?;
+        Ok(Self {
+            entries,
+            overrides: Vec::new(),
+        })
+    }
+
+    /// The parameters for one of a day's inputs, most specific key winning.
+    pub fn for_input(&self, day: &str, input: &InputKind) -> Params {
+        let mut scopes = vec![day.to_string()];
+        if input.is_sample() {
+            scopes.push(format!("{day}.sample"));
+        }
+        if let InputKind::Sample(Some(_)) = input {
+            scopes.push(format!("{day}.{input}"));
+        }
+
+        let mut values = HashMap::new();
+        for scope in &scopes {
+            for (key, value) in &self.entries {
+                let name = key
+                    .strip_prefix(scope.as_str())
+                    .and_then(|rest| rest.strip_prefix('.'));
+                if let Some(name) = name.filter(|name| !name.contains('.')) {
+                    values.insert(name.to_string(), value.clone());
+                }
+            }
+        }
+        values.extend(self.overrides.iter().cloned());
+
+        Params {
+            sample: input.is_sample(),
+            values,
+        }
+    }
+}
+
+/// Parses a `--param name=value` argument.
+pub fn parse_override(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((name, value)) if !name.trim().is_empty() => {
+            Ok((name.trim().to_string(), value.trim().to_string()))
+        }
+        _ => Err(format!("expected name=value, got {arg:?}")),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_for_input_precedence() {
+        let entries = [
+            ("day08.joins", "1000"),
+            ("day08.width", "71"),
+            ("day08.sample.joins", "10"),
+            ("day08.sample-small.joins", "5"),
+            ("day08.sample.extra.joins", "3"),
+            ("day09.joins", "2"),
+        ];
+        let mut config = ParamConfig {
+            entries: entries
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect(),
+            overrides: Vec::new(),
+        };
+        let joins = |config: &ParamConfig, input: &str| {
+            let params = config.for_input("day08", &input.parse().unwrap());
+            params.get::<usize>("joins").unwrap()
+        };
+
+        assert_eq!(joins(&config, "real"), 1000);
+        assert_eq!(joins(&config, "sample"), 10);
+        assert_eq!(joins(&config, "sample-small"), 5);
+        assert_eq!(joins(&config, "sample-other"), 10);
+        let params = config.for_input("day08", &InputKind::Sample(None));
+        assert!(params.is_sample());
+        assert_eq!(params.get::<usize>("width").unwrap(), 71);
+        assert!(params.get::<usize>("extra.joins").is_err());
+
+        config.overrides = vec![("joins".to_string(), "7".to_string())];
+        assert_eq!(joins(&config, "real"), 7);
+        assert_eq!(joins(&config, "sample-small"), 7);
+    }
+}
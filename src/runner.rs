@@ -0,0 +1,785 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        Arc, Mutex, PoisonError,
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use anyhow::{Context, anyhow, bail};
+use itertools::Itertools;
+
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    alloc::{self, AllocStats},
+    answers::{self, Answers},
+    bench::{BenchResult, Stats},
+    cancel::{self, CancelToken},
+    input::{self, InputKind, Inputs, Normalize, Selection},
+    params::{ParamConfig, Params},
+    trace::{self, Phase},
+};
+
+// Inspired by https://git.sr.ht/~gadanidis/aoc2024/tree/main/item/src/main.rs
+
+type DayFn = Arc<dyn Fn(&str, &Params) -> anyhow::Result<String> + Send + Sync + 'static>;
+
+/// Makes a random input for a day, for `fuzz`.
+pub type Generator = fn(&mut ChaCha8Rng) -> String;
+
+/// The variant registered as a day's `part1`/`part2`.
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// The variant `fuzz` checks the others against: a slow but obviously correct solution.
+pub const REFERENCE_VARIANT: &str = "reference";
+
+/// Stack size for threads parts run on, matching the main thread so recursive solutions behave
+/// the same with `--jobs` or `--timeout`.
+const STACK_SIZE: usize = 8 << 20;
+
+/// A value a part can return: an answer, or a `Result` of one for parts that can fail.
+pub trait IntoAnswer {
+    fn into_answer(self) -> anyhow::Result<String>;
+}
+
+macro_rules! display_answer {
+    ($($t:ty),*) => {
+        $(impl IntoAnswer for $t {
+            fn into_answer(self) -> anyhow::Result<String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+display_answer!(
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize,
+    num::BigInt,
+    num::BigUint
+);
+
+/// Text answers have to survive a round trip through an answers file, one `key = value` line.
+fn text_answer(answer: &str) -> anyhow::Result<String> {
+    if answer.is_empty() || answer.trim() != answer || answer.contains(['\n', '\r']) {
+        bail!(
+            "answer {answer:?} can't be recorded, it's empty or has surrounding whitespace or line breaks"
+        );
+    }
+    Ok(answer.to_string())
+}
+
+impl IntoAnswer for String {
+    fn into_answer(self) -> anyhow::Result<String> {
+        text_answer(&self)
+    }
+}
+
+impl IntoAnswer for &str {
+    fn into_answer(self) -> anyhow::Result<String> {
+        text_answer(self)
+    }
+}
+
+impl<T: IntoAnswer> IntoAnswer for anyhow::Result<T> {
+    fn into_answer(self) -> anyhow::Result<String> {
+        self?.into_answer()
+    }
+}
+
+/// How a result compares to the answer recorded in `answers/`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    New,
+    /// Another variant of the same part gave a different answer.
+    Disagree {
+        variant: String,
+        answer: String,
+    },
+    /// The part returned an error or panicked, or its input couldn't be read.
+    Error(String),
+    /// The part was still running when `--timeout` expired.
+    Timeout(Duration),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "PASS"),
+            Status::Fail { expected } => write!(f, "FAIL (expected {expected})"),
+            Status::New => write!(f, "NEW"),
+            Status::Disagree { variant, answer } => {
+                write!(f, "DISAGREE ({variant} gave {answer})")
+            }
+            Status::Error(message) => write!(f, "ERROR: {message}"),
+            Status::Timeout(limit) => write!(f, "TIMEOUT (after {}s)", limit.as_secs_f64()),
+        }
+    }
+}
+
+pub struct RunResult {
+    pub day: String,
+    pub part: usize,
+    pub variant: String,
+    pub input: InputKind,
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+    /// With several variants, how this one's time compares to the first's.
+    pub relative: Option<(String, f64)>,
+    /// What the part allocated, with the `alloc-stats` feature.
+    pub alloc: Option<AllocStats>,
+    /// Time spent in each of the part's spans, with `--trace`.
+    pub phases: Option<Vec<Phase>>,
+    pub status: Status,
+}
+
+impl RunResult {
+    /// Which part this is, naming the input unless it's the usual real or sample one, and the
+    /// variant unless it's the default.
+    pub fn label(&self) -> String {
+        let mut label = format!("{} part {}", self.day, self.part);
+        if self.variant != DEFAULT_VARIANT {
+            label += &format!(" ({})", self.variant);
+        }
+        if !matches!(self.input, InputKind::Real | InputKind::Sample(None)) {
+            label += &format!(" [{}]", self.input);
+        }
+        label
+    }
+}
+
+impl Display for RunResult {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.answer {
+            Some(answer) => {
+                write!(
+                    f,
+                    "{}: {}\t({}µs",
+                    self.label(),
+                    answer,
+                    self.elapsed.as_micros()
+                )?;
+                if let Some((variant, relative)) = &self.relative {
+                    write!(f, ", {relative:.2}x {variant}")?;
+                }
+                if let Some(alloc) = &self.alloc {
+                    write!(f, ", {alloc}")?;
+                }
+                write!(f, ")\t{}", self.status)?;
+            }
+            None => write!(f, "{}: {}", self.label(), self.status)?,
+        }
+        if let Some(phases) = &self.phases {
+            Phase::write_tree(f, phases, self.elapsed, 0)?;
+        }
+        Ok(())
+    }
+}
+
+/// One way of solving a part, registered from `partN` or `partN_<name>` in a day's module.
+struct Variant {
+    name: String,
+    part_fn: DayFn,
+}
+
+struct Day {
+    year: u32,
+    /// See [`input::day_key`].
+    key: String,
+    normalize: Normalize,
+    generator: Option<Generator>,
+    parts: [Vec<Variant>; 2],
+}
+
+pub struct Runner {
+    days: Vec<Day>,
+    /// Only time one part at a time, even when running days in parallel.
+    pub serial_timing: bool,
+    timing_lock: Mutex<()>,
+    /// Give up on a part after this long, leaving it to be cancelled on its worker thread.
+    pub timeout: Option<Duration>,
+    pub inputs: Inputs,
+    pub params: ParamConfig,
+    /// Record spans and report a per-phase breakdown of each part.
+    pub trace: bool,
+    /// Which variant of each part to run, or `all` to run and cross-check every variant.
+    pub variant: String,
+    /// Inputs already warned about, so each is only warned about once per run.
+    warned: Mutex<HashSet<(String, InputKind)>>,
+}
+
+impl Runner {
+    pub fn new() -> Self {
+        Self {
+            days: Vec::new(),
+            serial_timing: false,
+            timing_lock: Mutex::new(()),
+            timeout: None,
+            inputs: Inputs::new(Inputs::default_dir()),
+            params: ParamConfig::default(),
+            trace: false,
+            variant: DEFAULT_VARIANT.to_string(),
+            warned: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub fn register_day<T1, T2, F1, F2>(
+        &mut self,
+        year: u32,
+        name: &str,
+        normalize: Normalize,
+        part1: F1,
+        part2: F2,
+    ) where
+        F1: Fn(&str, &Params) -> T1,
+        F1: Send + Sync + 'static,
+        T1: IntoAnswer,
+        F2: Fn(&str, &Params) -> T2,
+        F2: Send + Sync + 'static,
+        T2: IntoAnswer,
+    {
+        let variant = |part_fn: DayFn| Variant {
+            name: DEFAULT_VARIANT.to_string(),
+            part_fn,
+        };
+        self.days.push(Day {
+            year,
+            key: input::day_key(year, name),
+            normalize,
+            generator: None,
+            parts: [
+                vec![variant(Arc::new(move |input, params| {
+                    part1(input, params).into_answer()
+                }))],
+                vec![variant(Arc::new(move |input, params| {
+                    part2(input, params).into_answer()
+                }))],
+            ],
+        });
+    }
+
+    /// Adds another way of solving a part of an already registered day.
+    pub fn register_variant<T, F>(
+        &mut self,
+        year: u32,
+        name: &str,
+        part: usize,
+        variant: &str,
+        f: F,
+    ) where
+        F: Fn(&str, &Params) -> T,
+        F: Send + Sync + 'static,
+        T: IntoAnswer,
+    {
+        let key = input::day_key(year, name);
+        let day = self
+            .days
+            .iter_mut()
+            .find(|day| day.key == key)
+            .unwrap_or_else(|| panic!("{key} must be registered before its variants"));
+        day.parts[part - 1].push(Variant {
+            name: variant.to_string(),
+            part_fn: Arc::new(move |input, params| f(input, params).into_answer()),
+        });
+    }
+
+    /// Sets how random inputs are made for an already registered day.
+    pub fn register_generator(&mut self, year: u32, name: &str, generator: Generator) {
+        let key = input::day_key(year, name);
+        let day = self
+            .days
+            .iter_mut()
+            .find(|day| day.key == key)
+            .unwrap_or_else(|| panic!("{key} must be registered before its generator"));
+        day.generator = Some(generator);
+    }
+
+    pub fn generator(&self, day: &str) -> anyhow::Result<Generator> {
+        self.day(day)?
+            .generator
+            .with_context(|| format!("{day} has no input generator, add a `pub fn generate`"))
+    }
+
+    /// Keys of the days registered for `year`.
+    pub fn day_names(&self, year: u32) -> impl Iterator<Item = &str> {
+        self.days
+            .iter()
+            .filter(move |day| day.year == year)
+            .map(|day| day.key.as_str())
+    }
+
+    pub fn years(&self) -> Vec<u32> {
+        self.days
+            .iter()
+            .map(|day| day.year)
+            .sorted()
+            .dedup()
+            .collect()
+    }
+
+    fn day(&self, day: &str) -> anyhow::Result<&Day> {
+        self.days
+            .iter()
+            .find(|registered| registered.key == day)
+            .with_context(|| format!("unknown day {day}"))
+    }
+
+    fn variants(&self, day: &str, part: usize) -> anyhow::Result<&[Variant]> {
+        let day = self.day(day)?;
+        match part {
+            1 | 2 => Ok(&day.parts[part - 1]),
+            _ => bail!("invalid part {part}"),
+        }
+    }
+
+    /// Names of a part's variants, default first.
+    pub fn variant_names(&self, day: &str, part: usize) -> anyhow::Result<Vec<String>> {
+        Ok(self
+            .variants(day, part)?
+            .iter()
+            .map(|variant| variant.name.clone())
+            .collect())
+    }
+
+    /// The variants of a part that [`Runner::variant`] selects.
+    /// A part without the named variant has none selected, so running several days with
+    /// `--variant` skips the days that don't have it.
+    pub fn selected_variants(&self, day: &str, part: usize) -> Vec<String> {
+        let Ok(names) = self.variant_names(day, part) else {
+            // an unknown day, which is reported when it's run
+            return vec![DEFAULT_VARIANT.to_string()];
+        };
+        match self.variant.as_str() {
+            "all" => names,
+            name => names
+                .into_iter()
+                .filter(|variant| variant == name)
+                .collect(),
+        }
+    }
+
+    fn part_fn(&self, day: &str, part: usize, variant: &str) -> anyhow::Result<&DayFn> {
+        self.variants(day, part)?
+            .iter()
+            .find(|registered| registered.name == variant)
+            .map(|registered| &registered.part_fn)
+            .with_context(|| format!("{day} part {part} has no variant {variant}"))
+    }
+
+    pub fn run(&self, day: &str, part: usize, variant: &str, input: &InputKind) -> RunResult {
+        let mut elapsed = Duration::ZERO;
+        let mut alloc = None;
+        let mut phases = None;
+        let answer = self.part_fn(day, part, variant).and_then(|part_fn| {
+            let contents = self.inputs.read(day, input)?;
+            let params = self.params.for_input(day, input);
+            let input = self.normalize(day, input, &contents)?;
+
+            let _guard = self.serial_timing.then(|| {
+                self.timing_lock
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+            });
+            let call = self.call(part_fn, input, &params);
+            (elapsed, alloc, phases) = (call.elapsed, call.alloc, call.phases);
+            call.answer
+        });
+
+        let status = match &answer {
+            Ok(answer) => verify(day, part, input, answer)
+                .unwrap_or_else(|e| Status::Error(format!("{:#}", e.context("verifying answer")))),
+            Err(e) => match e.downcast_ref::<TimedOut>() {
+                Some(TimedOut(limit)) => Status::Timeout(*limit),
+                None => Status::Error(format!("{e:#}")),
+            },
+        };
+
+        RunResult {
+            day: day.to_string(),
+            part,
+            variant: variant.to_string(),
+            input: input.clone(),
+            answer: answer.ok(),
+            elapsed,
+            relative: None,
+            alloc,
+            phases,
+            status,
+        }
+    }
+
+    /// Runs the given parts of each day on the selected inputs and variants, calling
+    /// `on_result` in day order. With more than one job, days run concurrently and each day's
+    /// results are held back until all earlier days have been reported.
+    pub fn run_all(
+        &self,
+        days: &[String],
+        parts: &[usize],
+        selection: &Selection,
+        jobs: usize,
+        mut on_result: impl FnMut(RunResult),
+    ) {
+        let run_day = |day: &str| {
+            let inputs = self.inputs.select(day, selection);
+            parts
+                .iter()
+                .cartesian_product(&inputs)
+                .flat_map(|(&part, input)| {
+                    let mut results = self
+                        .selected_variants(day, part)
+                        .iter()
+                        .map(|variant| self.run(day, part, variant, input))
+                        .collect_vec();
+                    cross_check(&mut results);
+                    results
+                })
+                .collect_vec()
+        };
+
+        if jobs <= 1 {
+            for day in days {
+                run_day(day).into_iter().for_each(&mut on_result);
+            }
+            return;
+        }
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(jobs)
+            .stack_size(STACK_SIZE)
+            .build()
+            .expect("Failed to build thread pool");
+        let (sender, receiver) = mpsc::channel();
+        pool.in_place_scope(|scope| {
+            for (index, day) in days.iter().enumerate() {
+                let sender = sender.clone();
+                let run_day = &run_day;
+                scope.spawn(move |_| {
+                    // the receiver outlives the scope, so this can't fail
+                    sender.send((index, run_day(day))).unwrap();
+                });
+            }
+            drop(sender);
+
+            let mut pending = BTreeMap::new();
+            let mut next = 0;
+            for (index, results) in receiver {
+                pending.insert(index, results);
+                while let Some(results) = pending.remove(&next) {
+                    results.into_iter().for_each(&mut on_result);
+                    next += 1;
+                }
+            }
+        });
+    }
+
+    /// Runs a part `warmup` times untimed, then `iterations` times timed.
+    pub fn bench(
+        &self,
+        day: &str,
+        part: usize,
+        variant: &str,
+        kind: &InputKind,
+        warmup: usize,
+        iterations: usize,
+    ) -> anyhow::Result<BenchResult> {
+        let part_fn = self.part_fn(day, part, variant)?;
+        let contents = self.inputs.read(day, kind)?;
+        let input = self.normalize(day, kind, &contents)?;
+        let params = self.params.for_input(day, kind);
+
+        let mut answer = None;
+        for _ in 0..warmup {
+            answer = Some(self.call(part_fn, input, &params).answer?);
+        }
+
+        let mut samples = Vec::new();
+        for _ in 0..iterations.max(1) {
+            let call = self.call(part_fn, input, &params);
+            samples.push(call.elapsed);
+            answer.get_or_insert(call.answer?);
+        }
+
+        Ok(BenchResult {
+            day: day.to_string(),
+            part,
+            variant: variant.to_string(),
+            input: kind.clone(),
+            answer: answer.unwrap(),
+            stats: Stats::from_samples(samples),
+            baseline: None,
+        })
+    }
+
+    /// Solves a part for an input that isn't from a file, with the parameters `kind` would get.
+    /// Panics are returned as errors, and no timing is kept.
+    pub fn solve(
+        &self,
+        day: &str,
+        part: usize,
+        variant: &str,
+        kind: &InputKind,
+        input: &str,
+    ) -> anyhow::Result<String> {
+        let part_fn = self.part_fn(day, part, variant)?;
+        let params = self.params.for_input(day, kind);
+        let input = self.day(day)?.normalize.apply(input);
+        call_timed(part_fn, input, &params, false).answer
+    }
+
+    /// Applies the day's normalization to its input, warning about whitespace problems the
+    /// first time each input is seen.
+    fn normalize<'a>(
+        &self,
+        day: &str,
+        kind: &InputKind,
+        contents: &'a str,
+    ) -> anyhow::Result<&'a str> {
+        let first_time = self
+            .warned
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert((day.to_string(), kind.clone()));
+        if first_time {
+            for warning in input::whitespace_warnings(contents) {
+                eprintln!("warning: {day} {kind} input {warning}");
+            }
+        }
+        Ok(self.day(day)?.normalize.apply(contents))
+    }
+
+    /// Calls and times a part, on a worker thread if there's a timeout.
+    fn call(&self, part_fn: &DayFn, input: &str, params: &Params) -> Call {
+        let Some(timeout) = self.timeout else {
+            return call_timed(part_fn, input, params, self.trace);
+        };
+
+        let token = CancelToken::default();
+        let (sender, receiver) = mpsc::channel();
+        let worker = {
+            let (part_fn, input, params, trace, token) = (
+                part_fn.clone(),
+                input.to_string(),
+                params.clone(),
+                self.trace,
+                token.clone(),
+            );
+            move || {
+                let result =
+                    cancel::with_token(token, || call_timed(&part_fn, &input, &params, trace));
+                // the runner may have stopped waiting for us
+                let _ = sender.send(result);
+            }
+        };
+        thread::Builder::new()
+            .stack_size(STACK_SIZE)
+            .spawn(worker)
+            .expect("Failed to spawn worker thread");
+
+        match receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(RecvTimeoutError::Timeout) => {
+                token.cancel();
+                Call {
+                    answer: Err(TimedOut(timeout).into()),
+                    elapsed: timeout,
+                    alloc: None,
+                    phases: None,
+                }
+            }
+            Err(RecvTimeoutError::Disconnected) => Call {
+                answer: Err(anyhow!("worker thread exited without a result")),
+                elapsed: Duration::ZERO,
+                alloc: None,
+                phases: None,
+            },
+        }
+    }
+}
+
+/// The outcome of calling a part once.
+struct Call {
+    answer: anyhow::Result<String>,
+    elapsed: Duration,
+    alloc: Option<AllocStats>,
+    phases: Option<Vec<Phase>>,
+}
+
+#[derive(Debug)]
+struct TimedOut(Duration);
+
+impl Display for TimedOut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "timed out after {}s", self.0.as_secs_f64())
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
+/// Calls and times a part, turning a panic into an error so one bad day doesn't take down the
+/// whole run.
+fn call_timed(part_fn: &DayFn, input: &str, params: &Params, trace: bool) -> Call {
+    let (((result, elapsed), phases), alloc) = alloc::measure(|| {
+        trace::record(trace, || {
+            let start = Instant::now();
+            let result = panic::catch_unwind(AssertUnwindSafe(|| part_fn(input, params)));
+            (result, start.elapsed())
+        })
+    });
+
+    let answer = match result {
+        Ok(answer) => answer,
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+                .unwrap_or("unknown panic");
+            Err(anyhow!("panicked: {message}"))
+        }
+    };
+    Call {
+        answer,
+        elapsed,
+        alloc,
+        phases,
+    }
+}
+
+/// Compares variants of the same part on the same input: any that disagree with the first are
+/// marked as such, and timings are given relative to the first.
+fn cross_check(results: &mut [RunResult]) {
+    let Some((first, rest)) = results.split_first_mut() else {
+        return;
+    };
+    let mut disagreement = None;
+    for result in rest {
+        let (Some(expected), Some(answer)) = (&first.answer, &result.answer) else {
+            continue;
+        };
+        result.relative = Some((
+            first.variant.clone(),
+            result.elapsed.as_secs_f64() / first.elapsed.as_secs_f64(),
+        ));
+        if answer != expected {
+            disagreement.get_or_insert_with(|| (result.variant.clone(), answer.clone()));
+            result.status = Status::Disagree {
+                variant: first.variant.clone(),
+                answer: expected.clone(),
+            };
+        }
+    }
+    // with nothing recorded to say which is right, the first is suspect too
+    if let Some((variant, answer)) = disagreement
+        && first.status == Status::New
+    {
+        first.status = Status::Disagree { variant, answer };
+    }
+}
+
+fn verify(day: &str, part: usize, input: &InputKind, answer: &str) -> anyhow::Result<Status> {
+    let Some(key) = answers::key(part, input) else {
+        return Ok(Status::New);
+    };
+    let answers = Answers::load(day)?;
+    Ok(match answers.get(&key) {
+        Some(expected) if expected == answer => Status::Pass,
+        Some(expected) => Status::Fail {
+            expected: expected.to_string(),
+        },
+        None => Status::New,
+    })
+}
+
+/// Writes a result's answer into its day's answers file.
+pub fn record(result: &RunResult) -> anyhow::Result<()> {
+    let Some(answer) = &result.answer else {
+        bail!("{} has no answer to record", result.label());
+    };
+    let Some(key) = answers::key(result.part, &result.input) else {
+        bail!("can't record answers for {}", result.input);
+    };
+    let mut answers = Answers::load(&result.day)?;
+    answers.set(&key, answer);
+    answers.save()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn result(variant: &str, answer: Option<&str>, status: Status) -> RunResult {
+        RunResult {
+            day: "day01".to_string(),
+            part: 1,
+            variant: variant.to_string(),
+            input: InputKind::Real,
+            answer: answer.map(str::to_string),
+            elapsed: Duration::from_millis(2),
+            relative: None,
+            alloc: None,
+            phases: None,
+            status,
+        }
+    }
+
+    fn disagree(variant: &str, answer: &str) -> Status {
+        Status::Disagree {
+            variant: variant.to_string(),
+            answer: answer.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_cross_check() {
+        // a recorded answer settles which is right, so only the odd one out disagrees
+        let mut results = [
+            result("default", Some("1"), Status::Pass),
+            result("fast", Some("1"), Status::Pass),
+            result(
+                "slow",
+                Some("2"),
+                Status::Fail {
+                    expected: "1".to_string(),
+                },
+            ),
+        ];
+        cross_check(&mut results);
+        assert_eq!(results[0].status, Status::Pass);
+        assert_eq!(results[1].status, Status::Pass);
+        assert_eq!(results[1].relative, Some(("default".to_string(), 1.0)));
+        assert_eq!(results[2].status, disagree("default", "1"));
+
+        // without one, the first is marked too, with the first answer that differed
+        let mut results = [
+            result("default", Some("1"), Status::New),
+            result("broken", None, Status::Error("oops".to_string())),
+            result("fast", Some("2"), Status::New),
+            result("slow", Some("3"), Status::New),
+        ];
+        cross_check(&mut results);
+        assert_eq!(results[0].status, disagree("fast", "2"));
+        assert_eq!(results[1].status, Status::Error("oops".to_string()));
+        assert_eq!(results[1].relative, None);
+        assert_eq!(results[2].status, disagree("default", "1"));
+        assert_eq!(results[3].status, disagree("default", "1"));
+    }
+}
@@ -0,0 +1,63 @@
+//! `new <day>`: sets up a new day from `src/day-template.rs`, with an empty sample input to
+//! paste into. The real input is left to be fetched on the first run.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, bail, ensure};
+use itertools::Itertools;
+
+use crate::input::{self, InputKind, Inputs};
+
+/// Accepts either `day07` or `7`.
+fn day_name(day: &str) -> anyhow::Result<String> {
+    let number = day.strip_prefix("day").unwrap_or(day);
+    let number: u32 = number
+        .parse()
+        .with_context(|| format!("expected a day like day07 or 7, got {day:?}"))?;
+    ensure!((1..=25).contains(&number), "day must be between 1 and 25");
+    Ok(format!("day{number:02}"))
+}
+
+/// Where a day's solution lives: `src/dayNN.rs`, or `src/yYYYY/dayNN.rs` for other years.
+pub fn source_path(year: u32, day: &str) -> PathBuf {
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+    let dir = if year == input::YEAR {
+        src
+    } else {
+        src.join(format!("y{year}"))
+    };
+    dir.join(day).with_extension("rs")
+}
+
+pub fn new_day(year: u32, day: &str, inputs: &Inputs) -> anyhow::Result<()> {
+    let day = day_name(day)?;
+    let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+
+    let source = source_path(year, &day);
+    let key = input::day_key(year, &day);
+    let sample = inputs.path(&key, &InputKind::Sample(None));
+
+    let existing = [&source, &sample]
+        .into_iter()
+        .filter(|path| path.exists())
+        .map(|path| path.display())
+        .join(", ");
+    if !existing.is_empty() {
+        bail!("refusing to overwrite {existing}");
+    }
+
+    let template =
+        std::fs::read_to_string(src.join("day-template.rs")).context("reading day-template.rs")?;
+    if let Some(parent) = source.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&source, template).with_context(|| format!("writing {}", source.display()))?;
+    if let Some(parent) = sample.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&sample, "").with_context(|| format!("writing {}", sample.display()))?;
+
+    println!("created {}", source.display());
+    println!("created {}", sample.display());
+    Ok(())
+}
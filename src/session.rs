@@ -0,0 +1,95 @@
+//! The HTTP client shared by fetching inputs and submitting answers: adventofcode.com (or a
+//! compatible server) authenticated with the session cookie copied out of the browser.
+
+use std::{path::Path, time::Duration};
+
+use anyhow::Context;
+
+#[derive(Clone)]
+pub struct Session {
+    base_url: String,
+    cookie: String,
+    agent: ureq::Agent,
+}
+
+impl Session {
+    pub fn new(base_url: impl Into<String>, cookie: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into(),
+            cookie: cookie.into(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent("github.com/jder/aoc2025")
+                .build(),
+        }
+    }
+
+    /// Reads the session cookie from a file, as copied out of the browser.
+    pub fn from_file(path: &Path) -> anyhow::Result<Self> {
+        let cookie = std::fs::read_to_string(path)
+            .with_context(|| format!("reading session from {}", path.display()))?;
+        Ok(Self::new("https://adventofcode.com", cookie.trim()))
+    }
+
+    /// The full URL of a path on the site, like `2025/day/7/input`.
+    pub fn url(&self, path: &str) -> String {
+        format!("{}/{path}", self.base_url)
+    }
+
+    pub fn get(&self, url: &str) -> ureq::Request {
+        self.authenticated(self.agent.get(url))
+    }
+
+    pub fn post(&self, url: &str) -> ureq::Request {
+        self.authenticated(self.agent.post(url))
+    }
+
+    fn authenticated(&self, request: ureq::Request) -> ureq::Request {
+        request.set("Cookie", &format!("session={}", self.cookie))
+    }
+}
+
+/// A local stand-in for the site in tests.
+#[cfg(test)]
+pub(crate) mod stub {
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+    };
+
+    /// Serves `responses` in order, one per connection, returning each request it saw: the
+    /// request line and headers, a blank line, then the body.
+    pub fn server(responses: Vec<&'static str>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+            for response in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some(value) = line.to_lowercase().strip_prefix("content-length:") {
+                        length = value.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                }
+                let mut body = vec![0; length];
+                reader.read_exact(&mut body).unwrap();
+                request.push('\n');
+                request.push_str(&String::from_utf8(body).unwrap());
+                requests.push(request);
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+            requests
+        });
+        (url, handle)
+    }
+}
@@ -0,0 +1,170 @@
+//! Submitting answers to the site. `submit` decides what to send and records the verdicts.
+
+use std::{fmt::Display, sync::LazyLock, time::Duration};
+
+use anyhow::{Context, bail};
+use regex::Regex;
+
+use crate::session::Session;
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// Submitted too soon after a previous answer, with how long is left to wait if given.
+    RateLimited(Option<Duration>),
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+}
+
+impl Verdict {
+    /// The answers key suffix a wrong answer is recorded under.
+    pub fn wrong_key(&self) -> Option<&'static str> {
+        match self {
+            Verdict::Incorrect => Some("wrong"),
+            Verdict::TooHigh => Some("too-high"),
+            Verdict::TooLow => Some("too-low"),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::TooHigh => write!(f, "incorrect, too high"),
+            Verdict::TooLow => write!(f, "incorrect, too low"),
+            Verdict::RateLimited(Some(wait)) => {
+                write!(f, "rate limited, wait {}s", wait.as_secs())
+            }
+            Verdict::RateLimited(None) => write!(f, "rate limited"),
+            Verdict::WrongLevel => write!(f, "part is already solved or still locked"),
+        }
+    }
+}
+
+pub trait Submitter {
+    /// Submits an answer for a part of a day of the given year.
+    fn submit(&self, year: u32, day: u32, part: usize, answer: &str) -> anyhow::Result<Verdict>;
+}
+
+/// Submits answers to the site through a [`Session`].
+pub struct HttpSubmitter {
+    session: Session,
+}
+
+impl HttpSubmitter {
+    pub fn new(session: Session) -> Self {
+        Self { session }
+    }
+}
+
+impl Submitter for HttpSubmitter {
+    fn submit(&self, year: u32, day: u32, part: usize, answer: &str) -> anyhow::Result<Verdict> {
+        let url = self.session.url(&format!("{year}/day/{day}/answer"));
+        let response = self
+            .session
+            .post(&url)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        match response {
+            Ok(response) => parse_response(
+                &response
+                    .into_string()
+                    .with_context(|| format!("reading response from {url}"))?,
+            ),
+            Err(ureq::Error::Status(404, _)) => bail!("{url}: puzzle isn't available yet"),
+            Err(ureq::Error::Status(status, _)) if (400..500).contains(&status) => {
+                bail!("{url}: got {status}, is the session cookie still valid?")
+            }
+            Err(e) => Err(e).with_context(|| format!("submitting to {url}")),
+        }
+    }
+}
+
+/// Picks the verdict out of the HTML page the site responds with.
+fn parse_response(body: &str) -> anyhow::Result<Verdict> {
+    static WAIT: LazyLock<Regex> =
+        LazyLock::new(|| Regex::new(r"You have (?:(\d+)m )?(\d+)s left to wait").unwrap());
+
+    if body.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if body.contains("That's not the right answer") {
+        Ok(if body.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if body.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Incorrect
+        })
+    } else if body.contains("You gave an answer too recently") {
+        let wait = WAIT.captures(body).map(|captures| {
+            let minutes = captures.get(1).map_or(0, |m| m.as_str().parse().unwrap());
+            let seconds: u64 = captures[2].parse().unwrap();
+            Duration::from_secs(minutes * 60 + seconds)
+        });
+        Ok(Verdict::RateLimited(wait))
+    } else if body.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::WrongLevel)
+    } else {
+        bail!("didn't recognize the response to the submission")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::session::stub;
+
+    fn page(article: &str) -> String {
+        format!("<html><body><main><article><p>{article}</p></article></main></body></html>")
+    }
+
+    #[test]
+    fn test_http_submit() {
+        let (url, server) = stub::server(vec![
+            "HTTP/1.1 200 OK\r\nContent-Length: 44\r\nConnection: close\r\n\r\n\
+             <p>That's the right answer! You are one gold",
+        ]);
+        let submitter = HttpSubmitter::new(Session::new(url, "abc123"));
+        assert_eq!(
+            submitter.submit(2025, 7, 2, "1234").unwrap(),
+            Verdict::Correct
+        );
+
+        let request = &server.join().unwrap()[0];
+        assert!(request.starts_with("POST /2025/day/7/answer "), "{request}");
+        assert!(request.contains("Cookie: session=abc123\r\n"), "{request}");
+        assert!(request.ends_with("\nlevel=2&answer=1234"), "{request}");
+    }
+
+    #[test]
+    fn test_parse_response() {
+        let verdict = |article: &str| parse_response(&page(article)).unwrap();
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too high."),
+            Verdict::TooHigh
+        );
+        assert_eq!(
+            verdict("That's not the right answer; your answer is too low."),
+            Verdict::TooLow
+        );
+        assert_eq!(
+            verdict("That's not the right answer. If you're stuck..."),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            verdict("You gave an answer too recently. You have 1m 5s left to wait."),
+            Verdict::RateLimited(Some(Duration::from_secs(65)))
+        );
+        assert_eq!(
+            verdict("You don't seem to be solving the right level."),
+            Verdict::WrongLevel
+        );
+        assert!(parse_response(&page("Something else")).is_err());
+    }
+}
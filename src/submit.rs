@@ -0,0 +1,124 @@
+//! `submit <day> <part>`: runs a part on the real input and submits its answer through a
+//! [`Submitter`], recording the verdict in `answers/`.
+//!
+//! Correct answers are recorded like `record` does. Wrong ones are kept as `partN.wrong`,
+//! `partN.too-high` or `partN.too-low` lines, and an answer is refused without submitting if
+//! it's already known to be wrong, or is outside the bounds given by earlier too-high and
+//! too-low verdicts.
+
+use anyhow::{Context, bail};
+use num::BigInt;
+
+use crate::{
+    answers::{self, Answers},
+    input::{self, InputKind},
+    runner::{DEFAULT_VARIANT, Runner},
+    submission::{Submitter, Verdict},
+};
+
+/// Why `answer` shouldn't be submitted given what's already recorded for the part, if it
+/// shouldn't.
+fn refusal(answers: &Answers, part: usize, answer: &str) -> Option<String> {
+    let key = format!("part{part}");
+    if let Some(correct) = answers.get(&key) {
+        return Some(if correct == answer {
+            format!("{answer} is already recorded as correct")
+        } else {
+            format!("part {part} is already solved with {correct}")
+        });
+    }
+    for verdict in [Verdict::Incorrect, Verdict::TooHigh, Verdict::TooLow] {
+        let suffix = verdict.wrong_key().unwrap();
+        if answers
+            .get_all(&format!("{key}.{suffix}"))
+            .any(|wrong| wrong == answer)
+        {
+            return Some(format!("{answer} was already submitted: {verdict}"));
+        }
+    }
+
+    let answer = answer.parse::<BigInt>().ok()?;
+    let bounds = |suffix| {
+        answers
+            .get_all(&format!("{key}.{suffix}"))
+            .filter_map(|bound| bound.parse::<BigInt>().ok())
+            .collect::<Vec<_>>()
+    };
+    if let Some(high) = bounds("too-high")
+        .into_iter()
+        .filter(|high| answer >= *high)
+        .min()
+    {
+        return Some(format!("{answer} isn't below {high}, which was too high"));
+    }
+    if let Some(low) = bounds("too-low")
+        .into_iter()
+        .filter(|low| answer <= *low)
+        .max()
+    {
+        return Some(format!("{answer} isn't above {low}, which was too low"));
+    }
+    None
+}
+
+/// Runs a part on the real input and submits its answer, unless it's known to be wrong.
+pub fn submit(
+    runner: &Runner,
+    submitter: &dyn Submitter,
+    day: &str,
+    part: usize,
+) -> anyhow::Result<()> {
+    let result = runner.run(day, part, DEFAULT_VARIANT, &InputKind::Real);
+    println!("{result}");
+    let Some(answer) = &result.answer else {
+        bail!("{} has no answer to submit", result.label());
+    };
+
+    let mut answers = Answers::load(day)?;
+    if let Some(reason) = refusal(&answers, part, answer) {
+        bail!("not submitting: {reason}");
+    }
+
+    let (year, name) = input::split_day_key(day);
+    let number = name
+        .strip_prefix("day")
+        .and_then(|number| number.parse().ok())
+        .with_context(|| format!("can't submit for {day}, expected a name like day07"))?;
+    let verdict = submitter.submit(year, number, part, answer)?;
+    println!("{} {answer}: {verdict}", result.label());
+
+    let key = answers::key(part, &InputKind::Real).unwrap();
+    if verdict == Verdict::Correct {
+        answers.set(&key, answer);
+    } else if let Some(suffix) = verdict.wrong_key() {
+        answers.add(&format!("{key}.{suffix}"), answer);
+    } else {
+        bail!("{verdict}");
+    }
+    answers.save()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_refuses_known_wrong_answers() {
+        // never saved, so nothing is written to answers/
+        let mut answers = Answers::load("day99").unwrap();
+        answers.add("part1.wrong", "17");
+        answers.add("part1.too-high", "100");
+        answers.add("part1.too-low", "20");
+
+        assert!(refusal(&answers, 1, "17").is_some());
+        assert!(refusal(&answers, 1, "100").is_some());
+        assert!(refusal(&answers, 1, "150").is_some());
+        assert!(refusal(&answers, 1, "20").is_some());
+        assert!(refusal(&answers, 1, "50").is_none());
+        assert!(refusal(&answers, 2, "17").is_none());
+
+        answers.set("part1", "50");
+        assert!(refusal(&answers, 1, "50").is_some());
+        assert!(refusal(&answers, 1, "60").is_some());
+    }
+}
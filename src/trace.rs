@@ -0,0 +1,161 @@
+//! Timing of phases within a part, shown under each result with `--trace`.
+//!
+//! A day wraps a phase in a [`span`], which times it until the guard is dropped. Spans nest,
+//! and spans with the same name under the same parent are added together, so a span inside a
+//! loop shows up once with its total time and count. Only spans on the thread running the part
+//! are recorded, not ones inside rayon tasks.
+
+use std::{
+    cell::RefCell,
+    time::{Duration, Instant},
+};
+
+#[derive(Debug, Clone)]
+pub struct Phase {
+    pub name: &'static str,
+    pub elapsed: Duration,
+    pub count: usize,
+    pub children: Vec<Phase>,
+}
+
+impl Phase {
+    /// Writes `phases` as an indented tree, with each phase's share of `total`.
+    pub fn write_tree(
+        f: &mut impl std::fmt::Write,
+        phases: &[Phase],
+        total: Duration,
+        depth: usize,
+    ) -> std::fmt::Result {
+        for phase in phases {
+            write!(
+                f,
+                "\n{:indent$}{}: {}µs ({:.1}%)",
+                "",
+                phase.name,
+                phase.elapsed.as_micros(),
+                phase.elapsed.as_secs_f64() / total.as_secs_f64() * 100.0,
+                indent = 4 + depth * 2
+            )?;
+            if phase.count > 1 {
+                write!(f, " x{}", phase.count)?;
+            }
+            Self::write_tree(f, &phase.children, total, depth + 1)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Default)]
+struct Recorder {
+    phases: Vec<Phase>,
+    /// Indices from `phases` down to the innermost open span.
+    open: Vec<usize>,
+}
+
+impl Recorder {
+    fn children(&mut self) -> &mut Vec<Phase> {
+        let mut children = &mut self.phases;
+        for &index in &self.open {
+            children = &mut children[index].children;
+        }
+        children
+    }
+
+    fn enter(&mut self, name: &'static str) {
+        let children = self.children();
+        let index = match children.iter().position(|phase| phase.name == name) {
+            Some(index) => index,
+            None => {
+                children.push(Phase {
+                    name,
+                    elapsed: Duration::ZERO,
+                    count: 0,
+                    children: Vec::new(),
+                });
+                children.len() - 1
+            }
+        };
+        self.open.push(index);
+    }
+
+    fn exit(&mut self, elapsed: Duration) {
+        let Some(index) = self.open.pop() else {
+            return;
+        };
+        let phase = &mut self.children()[index];
+        phase.elapsed += elapsed;
+        phase.count += 1;
+    }
+}
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// Times a phase until the returned guard is dropped. Does nothing unless tracing.
+pub fn span(name: &'static str) -> Span {
+    let recording = RECORDER.with_borrow_mut(|recorder| match recorder {
+        Some(recorder) => {
+            recorder.enter(name);
+            true
+        }
+        None => false,
+    });
+    Span {
+        start: recording.then(Instant::now),
+    }
+}
+
+#[must_use = "a span ends when it's dropped"]
+pub struct Span {
+    start: Option<Instant>,
+}
+
+impl Drop for Span {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            let elapsed = start.elapsed();
+            RECORDER.with_borrow_mut(|recorder| {
+                if let Some(recorder) = recorder {
+                    recorder.exit(elapsed);
+                }
+            });
+        }
+    }
+}
+
+/// Runs `f`, recording its spans if `enabled`.
+pub fn record<T>(enabled: bool, f: impl FnOnce() -> T) -> (T, Option<Vec<Phase>>) {
+    if !enabled {
+        return (f(), None);
+    }
+    RECORDER.with_borrow_mut(|recorder| *recorder = Some(Recorder::default()));
+    let result = f();
+    let recorder = RECORDER.with_borrow_mut(Option::take);
+    (result, recorder.map(|recorder| recorder.phases))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_nested_spans_merge_by_name() {
+        let ((), phases) = record(true, || {
+            let _outer = span("outer");
+            for _ in 0..3 {
+                let _inner = span("inner");
+            }
+        });
+        let phases = phases.unwrap();
+        assert_eq!(phases.len(), 1);
+        assert_eq!((phases[0].name, phases[0].count), ("outer", 1));
+        let inner = &phases[0].children;
+        assert_eq!(inner.len(), 1);
+        assert_eq!((inner[0].name, inner[0].count), ("inner", 3));
+
+        // spans outside a recording are ignored
+        drop(span("ignored"));
+        assert!(record(false, || span("ignored")).1.is_none());
+    }
+}
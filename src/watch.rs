@@ -0,0 +1,168 @@
+//! `watch <day>`: rebuilds and re-runs a day whenever its source or inputs change, showing
+//! how each answer changed since the previous run.
+//!
+//! The running binary can't rebuild itself, so each run is a `cargo run` of the day with
+//! `--output json`, whose results are compared with the last ones seen.
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use anyhow::Context;
+use itertools::Itertools;
+
+use crate::{
+    input::{self, InputKind, Inputs},
+    output::Record,
+    runner::RunResult,
+    scaffold,
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+pub struct Watch {
+    pub year: u32,
+    pub day: String,
+    pub part: Option<usize>,
+    pub sample: bool,
+    pub inputs: Inputs,
+    /// Passed on to each run, for fetching inputs that aren't cached yet.
+    pub session_file: Option<PathBuf>,
+}
+
+impl Watch {
+    fn key(&self) -> String {
+        input::day_key(self.year, &self.day)
+    }
+
+    fn watched_files(&self) -> Vec<PathBuf> {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let src = root.join("src");
+        let key = self.key();
+        let mut files = vec![
+            scaffold::source_path(self.year, &self.day),
+            src.join("grid.rs"),
+            src.join("graph.rs"),
+            root.join("params.txt"),
+            self.inputs.path(&key, &InputKind::Real),
+        ];
+        files.extend(
+            self.inputs
+                .samples(&key)
+                .iter()
+                .map(|sample| self.inputs.path(&key, sample)),
+        );
+        files
+    }
+
+    /// Modification times of the watched files, `None` for ones that don't exist.
+    fn snapshot(&self) -> HashMap<PathBuf, Option<SystemTime>> {
+        self.watched_files()
+            .into_iter()
+            .map(|path| {
+                let modified = std::fs::metadata(&path).and_then(|m| m.modified()).ok();
+                (path, modified)
+            })
+            .collect()
+    }
+
+    /// Builds and runs the day, returning its results or `None` if the build failed.
+    fn run_once(&self) -> anyhow::Result<Option<Vec<RunResult>>> {
+        let cargo = std::env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+        let manifest = Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml");
+
+        let built = Command::new(&cargo)
+            .args(["build", "--quiet", "--manifest-path"])
+            .arg(&manifest)
+            .status()
+            .context("running cargo build")?;
+        if !built.success() {
+            return Ok(None);
+        }
+
+        let mut run = Command::new(&cargo);
+        run.args(["run", "--quiet", "--manifest-path"])
+            .arg(&manifest)
+            .args(["--", &self.day, "--output", "json", "--input-dir"])
+            .arg(self.inputs.dir())
+            .args(["--year", &self.year.to_string()]);
+        if let Some(part) = self.part {
+            run.args(["--part", &part.to_string()]);
+        }
+        if self.sample {
+            run.arg("--sample");
+        }
+        if let Some(session_file) = &self.session_file {
+            run.arg("--session-file").arg(session_file);
+        }
+        let output = run
+            .stderr(Stdio::inherit())
+            .output()
+            .context("running cargo run")?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let results = stdout
+            .lines()
+            .map(|line| RunResult::try_from(serde_json::from_str::<Record>(line)?))
+            .collect::<anyhow::Result<Vec<_>>>()
+            .context("parsing results")?;
+        Ok(Some(results))
+    }
+
+    pub fn run(&self) -> anyhow::Result<()> {
+        let mut previous: HashMap<(usize, String), Option<String>> = HashMap::new();
+        let mut snapshot = self.snapshot();
+        let mut changed: Vec<PathBuf> = vec![];
+        loop {
+            match changed.as_slice() {
+                [] => println!("--- running {}", self.key()),
+                changed => println!(
+                    "--- {} changed, re-running {}",
+                    changed.iter().map(|path| path.display()).join(", "),
+                    self.key()
+                ),
+            }
+
+            match self.run_once() {
+                Err(e) => println!("run failed, waiting for changes: {e:#}"),
+                Ok(None) => println!("build failed, waiting for changes"),
+                Ok(Some(results)) => {
+                    for result in results {
+                        let key = (result.part, result.input.to_string());
+                        let was = previous.insert(key, result.answer.clone());
+                        println!("{result}{}", change(&result, was));
+                    }
+                }
+            }
+
+            loop {
+                thread::sleep(POLL_INTERVAL);
+                let next = self.snapshot();
+                changed = next
+                    .iter()
+                    .filter(|(path, modified)| snapshot.get(*path) != Some(modified))
+                    .map(|(path, _)| path.clone())
+                    .collect();
+                snapshot = next;
+                if !changed.is_empty() {
+                    changed.sort();
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// How a result's answer changed since the last run, to follow its usual line.
+fn change(result: &RunResult, was: Option<Option<String>>) -> String {
+    match was {
+        None => String::new(),
+        Some(was) if was == result.answer => "\t(unchanged)".to_string(),
+        Some(Some(was)) => format!("\t(was {was})"),
+        Some(None) => "\t(was no answer)".to_string(),
+    }
+}